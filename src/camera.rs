@@ -116,6 +116,7 @@ pub struct CursorRaycast<'w, 's> {
     main_camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<CameraController>>,
     main_window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
     rapier: Res<'w, RapierContext>,
+    collision_groups: Query<'w, 's, &'static CollisionGroups>,
     ui: UiQuery<'w, 's>,
 }
 
@@ -149,12 +150,26 @@ impl<'w, 's> CursorRaycast<'w, 's> {
 
     /// Return the point on the ground plane that the mouse is over
     pub fn ground_point(&self) -> Option<Vec3> {
-        let ground_filter = QueryFilter::default().groups(CollisionGroups::new(
-            CollisionLayer::Ground.into(),
-            CollisionLayer::Ground.into(),
-        ));
+        self.raycast(CollisionLayer::Ground.filter())
+            .map(|hit| hit.1)
+    }
+
+    /// Return the point on either the ground or a water surface that the mouse is over, along with which was hit
+    pub fn surface_point(&self) -> Option<(Vec3, CollisionLayer)> {
+        let surfaces = Group::from(CollisionLayer::Ground) | Group::from(CollisionLayer::Water);
+        let filter = QueryFilter::default().groups(CollisionGroups::new(Group::ALL, surfaces));
+
+        let (entity, point) = self.raycast(filter)?;
 
-        self.raycast(ground_filter).map(|hit| hit.1)
+        let is_water = self
+            .collision_groups
+            .get(entity)
+            .is_ok_and(|groups| groups.memberships.contains(CollisionLayer::Water.into()));
+
+        match is_water {
+            true => Some((point, CollisionLayer::Water)),
+            false => Some((point, CollisionLayer::Ground)),
+        }
     }
 
     /// Return the nearest entity clicked on by the cursor
@@ -0,0 +1,313 @@
+use bevy::{app::RunFixedUpdateLoop, ecs::schedule::ScheduleLabel, prelude::*};
+
+pub struct ClockPlugin;
+impl Plugin for ClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_schedule(Simulation)
+            .init_resource::<ZooClock>()
+            .add_systems(RunFixedUpdateLoop, run_simulation)
+            .add_systems(Update, handle_speed_shortcuts);
+    }
+}
+
+// constants for the zoo clock
+/// Game seconds that pass in each step of the simulation
+const TIMESTEP: f32 = 1.0 / 30.0;
+/// Most steps that can be simulated in a single frame, so that slow frames don't snowball
+const MAX_STEPS_PER_FRAME: u32 = 16;
+/// Game seconds in a day, each passing in a real second at normal speed
+const SECONDS_PER_DAY: f32 = 10.0;
+pub const DAYS_PER_MONTH: u32 = 30;
+const MONTHS_PER_YEAR: u32 = 12;
+const DAYS_PER_YEAR: u32 = DAYS_PER_MONTH * MONTHS_PER_YEAR;
+const MONTH_NAMES: [&str; MONTHS_PER_YEAR as usize] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Schedule holding every system that simulates the zoo, stepped at a fixed rate scaled by the simulation speed
+///
+/// Systems in this schedule should measure time with the `ZooClock` rather than `Time`. Steps don't run in every
+/// frame and events only last for two, so events passed between this schedule and `Update` are read in `Update`
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Simulation;
+
+/// Rates that the simulation can be run at
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimulationSpeed {
+    Paused,
+    Normal,
+    Fast,
+    Fastest,
+}
+
+impl SimulationSpeed {
+    pub const ALL: [SimulationSpeed; 4] = [
+        SimulationSpeed::Paused,
+        SimulationSpeed::Normal,
+        SimulationSpeed::Fast,
+        SimulationSpeed::Fastest,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SimulationSpeed::Paused => "||",
+            SimulationSpeed::Normal => "1x",
+            SimulationSpeed::Fast => "2x",
+            SimulationSpeed::Fastest => "4x",
+        }
+    }
+
+    /// Game seconds that pass for each real second
+    pub fn multiplier(&self) -> f32 {
+        match self {
+            SimulationSpeed::Paused => 0.0,
+            SimulationSpeed::Normal => 1.0,
+            SimulationSpeed::Fast => 2.0,
+            SimulationSpeed::Fastest => 4.0,
+        }
+    }
+
+    /// Key that switches the simulation to this speed, with space toggling the pause instead
+    pub fn shortcut(&self) -> KeyCode {
+        match self {
+            SimulationSpeed::Paused => KeyCode::Space,
+            SimulationSpeed::Normal => KeyCode::Key1,
+            SimulationSpeed::Fast => KeyCode::Key2,
+            SimulationSpeed::Fastest => KeyCode::Key3,
+        }
+    }
+}
+
+/// Calendar of the zoo, and the speed that time passes within it
+#[derive(Resource)]
+pub struct ZooClock {
+    speed: SimulationSpeed,
+    /// Speed that the simulation resumes at once unpaused
+    resume_speed: SimulationSpeed,
+    /// Whole days that have passed since the zoo opened
+    days: u32,
+    /// Game seconds into the current day
+    time_of_day: f32,
+    /// Game seconds built up from real time that are still waiting to be simulated
+    accumulated: f32,
+    /// Whether the current step started a new day
+    new_day: bool,
+    /// Whether time has stopped for good, such as once the game is over
+    stopped: bool,
+}
+
+impl Default for ZooClock {
+    fn default() -> Self {
+        Self {
+            speed: SimulationSpeed::Normal,
+            resume_speed: SimulationSpeed::Normal,
+            days: 0,
+            time_of_day: 0.0,
+            accumulated: 0.0,
+            new_day: false,
+            stopped: false,
+        }
+    }
+}
+
+impl ZooClock {
+    pub fn speed(&self) -> SimulationSpeed {
+        self.speed
+    }
+
+    /// Changes the speed of the simulation, remembering the last running speed for when it is unpaused
+    pub fn set_speed(&mut self, speed: SimulationSpeed) {
+        if self.stopped {
+            return;
+        }
+
+        if speed != SimulationSpeed::Paused {
+            self.resume_speed = speed;
+        }
+        self.speed = speed;
+    }
+
+    /// Pauses the simulation, or resumes it at the speed it was running at before
+    pub fn toggle_pause(&mut self) {
+        if self.stopped {
+            return;
+        }
+
+        match self.speed {
+            SimulationSpeed::Paused => self.speed = self.resume_speed,
+            _ => self.speed = SimulationSpeed::Paused,
+        }
+    }
+
+    /// Pauses the simulation for good, ignoring any later changes in speed
+    pub fn stop(&mut self) {
+        self.speed = SimulationSpeed::Paused;
+        self.accumulated = 0.0;
+        self.stopped = true;
+    }
+
+    /// Game seconds that pass in each step of the simulation
+    pub fn delta_seconds(&self) -> f32 {
+        TIMESTEP
+    }
+
+    /// Fraction of a year that passes in each step of the simulation
+    pub fn delta_years(&self) -> f32 {
+        TIMESTEP / (SECONDS_PER_DAY * DAYS_PER_YEAR as f32)
+    }
+
+    /// Day of the current month, starting from 1
+    pub fn day(&self) -> u32 {
+        self.days % DAYS_PER_MONTH + 1
+    }
+
+    /// Month of the current year, starting from 1
+    pub fn month(&self) -> u32 {
+        self.days / DAYS_PER_MONTH % MONTHS_PER_YEAR + 1
+    }
+
+    /// Years that the zoo has been open for, starting from 1
+    pub fn year(&self) -> u32 {
+        self.days / DAYS_PER_YEAR + 1
+    }
+
+    /// Returns true if a new day started this step
+    pub fn is_new_day(&self) -> bool {
+        self.new_day
+    }
+
+    /// Returns true if a new month started this step
+    pub fn is_new_month(&self) -> bool {
+        self.new_day && self.day() == 1
+    }
+
+    /// Current date written out for the ui, such as "3 Feb, Year 2"
+    pub fn date(&self) -> String {
+        format!(
+            "{} {}, Year {}",
+            self.day(),
+            MONTH_NAMES[self.month() as usize - 1],
+            self.year()
+        )
+    }
+
+    /// Moves the calendar forward by a single step
+    fn step(&mut self) {
+        self.time_of_day += TIMESTEP;
+        self.new_day = self.time_of_day >= SECONDS_PER_DAY;
+
+        if self.new_day {
+            self.time_of_day -= SECONDS_PER_DAY;
+            self.days += 1;
+        }
+    }
+}
+
+/// Runs as many steps of the simulation as the time since the last frame calls for at the current speed
+fn run_simulation(world: &mut World) {
+    let delta = world.resource::<Time>().delta_seconds();
+
+    let mut clock = world.resource_mut::<ZooClock>();
+    let max_accumulated = TIMESTEP * MAX_STEPS_PER_FRAME as f32;
+    clock.accumulated = (clock.accumulated + delta * clock.speed.multiplier()).min(max_accumulated);
+
+    while clock.accumulated >= TIMESTEP {
+        clock.accumulated -= TIMESTEP;
+        clock.step();
+
+        world.run_schedule(Simulation);
+        clock = world.resource_mut::<ZooClock>();
+    }
+}
+
+/// Changes the speed of the simulation from the keyboard
+fn handle_speed_shortcuts(keys: Res<Input<KeyCode>>, mut clock: ResMut<ZooClock>) {
+    for speed in SimulationSpeed::ALL {
+        if keys.just_pressed(speed.shortcut()) {
+            match speed {
+                SimulationSpeed::Paused => clock.toggle_pause(),
+                _ => clock.set_speed(speed),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps the clock until the given number of days have started, returning how many steps started a new month
+    fn advance_days(clock: &mut ZooClock, days: u32) -> u32 {
+        let mut new_months = 0;
+        for _ in 0..days {
+            loop {
+                clock.step();
+                if clock.is_new_day() {
+                    break;
+                }
+            }
+            new_months += clock.is_new_month() as u32;
+        }
+        new_months
+    }
+
+    #[test]
+    fn clock_starts_on_the_first_day() {
+        let clock = ZooClock::default();
+
+        assert_eq!((clock.day(), clock.month(), clock.year()), (1, 1, 1));
+        assert_eq!(clock.date(), "1 Jan, Year 1");
+    }
+
+    #[test]
+    fn new_days_start_for_a_single_step() {
+        let mut clock = ZooClock::default();
+
+        let steps = (SECONDS_PER_DAY / TIMESTEP).round() as u32;
+        let new_days = (0..steps * 3)
+            .filter(|_| {
+                clock.step();
+                clock.is_new_day()
+            })
+            .count();
+
+        assert_eq!(new_days, 3);
+        assert_eq!(clock.day(), 4);
+    }
+
+    #[test]
+    fn months_roll_over_after_their_last_day() {
+        let mut clock = ZooClock::default();
+
+        assert_eq!(advance_days(&mut clock, DAYS_PER_MONTH - 1), 0);
+        assert_eq!((clock.day(), clock.month()), (DAYS_PER_MONTH, 1));
+
+        assert_eq!(advance_days(&mut clock, 1), 1);
+        assert!(clock.is_new_month());
+        assert_eq!((clock.day(), clock.month()), (1, 2));
+        assert_eq!(clock.date(), "1 Feb, Year 1");
+    }
+
+    #[test]
+    fn years_roll_over_after_their_last_month() {
+        let mut clock = ZooClock::default();
+
+        assert_eq!(
+            advance_days(&mut clock, DAYS_PER_YEAR - 1),
+            MONTHS_PER_YEAR - 1
+        );
+        assert_eq!((clock.month(), clock.year()), (MONTHS_PER_YEAR, 1));
+
+        assert_eq!(advance_days(&mut clock, 1), 1);
+        assert_eq!((clock.day(), clock.month(), clock.year()), (1, 1, 2));
+        assert_eq!(clock.date(), "1 Jan, Year 2");
+    }
+
+    #[test]
+    fn a_years_worth_of_steps_adds_up_to_a_year() {
+        let clock = ZooClock::default();
+
+        let steps = SECONDS_PER_DAY * DAYS_PER_YEAR as f32 / clock.delta_seconds();
+        assert!((clock.delta_years() * steps - 1.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    clock::{Simulation, ZooClock},
+    zoo::ZooBalanceChange,
+    Currency,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub struct LedgerPlugin;
+impl Plugin for LedgerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ZooLedger>()
+            .add_systems(Update, record_balance_changes)
+            .add_systems(Simulation, close_month);
+    }
+}
+
+/// Kinds of income and expenses that the zoo's finances are broken down into
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LedgerCategory {
+    /// Building paths, facilities and the entrance, and shaping the terrain
+    Construction,
+    Land,
+    /// Buying and selling animals
+    Animals,
+    Admissions,
+    /// Food, drinks and gifts sold to guests
+    Sales,
+    /// Animal feed, facility stock and materials used by staff
+    Supplies,
+    Veterinary,
+    Wages,
+    /// Daily running costs of animals and placed objects
+    Upkeep,
+    Marketing,
+    /// Money borrowed and paid back, not including interest
+    Loans,
+    Interest,
+}
+
+impl LedgerCategory {
+    pub const ALL: [LedgerCategory; 12] = [
+        LedgerCategory::Construction,
+        LedgerCategory::Land,
+        LedgerCategory::Animals,
+        LedgerCategory::Admissions,
+        LedgerCategory::Sales,
+        LedgerCategory::Supplies,
+        LedgerCategory::Veterinary,
+        LedgerCategory::Wages,
+        LedgerCategory::Upkeep,
+        LedgerCategory::Marketing,
+        LedgerCategory::Loans,
+        LedgerCategory::Interest,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LedgerCategory::Construction => "Construction",
+            LedgerCategory::Land => "Land",
+            LedgerCategory::Animals => "Animals",
+            LedgerCategory::Admissions => "Admissions",
+            LedgerCategory::Sales => "Sales",
+            LedgerCategory::Supplies => "Supplies",
+            LedgerCategory::Veterinary => "Veterinary",
+            LedgerCategory::Wages => "Wages",
+            LedgerCategory::Upkeep => "Upkeep",
+            LedgerCategory::Marketing => "Marketing",
+            LedgerCategory::Loans => "Loans",
+            LedgerCategory::Interest => "Interest",
+        }
+    }
+}
+
+/// Record of the zoo's income and expenses by category, for this month and the last
+#[derive(Resource, Default)]
+pub struct ZooLedger {
+    this_month: HashMap<LedgerCategory, Currency>,
+    last_month: HashMap<LedgerCategory, Currency>,
+    /// Running costs expected over a month at the zoo's current size, from upkeep and wages
+    pub projected_expenses: Currency,
+}
+
+impl ZooLedger {
+    /// Returns the total amount gained in the category this month, which is negative for expenses
+    pub fn this_month(&self, category: LedgerCategory) -> Currency {
+        self.this_month.get(&category).copied().unwrap_or_default()
+    }
+
+    /// Returns the total amount gained in the category last month, which is negative for expenses
+    pub fn last_month(&self, category: LedgerCategory) -> Currency {
+        self.last_month.get(&category).copied().unwrap_or_default()
+    }
+
+    /// Returns the overall profit made this month, leaving out loan principal since it is neither earned nor spent
+    pub fn profit(&self) -> Currency {
+        self.this_month
+            .iter()
+            .filter(|(category, _)| **category != LedgerCategory::Loans)
+            .map(|(_, amount)| amount)
+            .sum()
+    }
+}
+
+/// Writes every change to the zoo's balance into the ledger
+fn record_balance_changes(
+    mut ledger: ResMut<ZooLedger>,
+    mut balance_changes: EventReader<ZooBalanceChange>,
+) {
+    for change in balance_changes.iter() {
+        *ledger.this_month.entry(change.category).or_default() += change.amount;
+    }
+}
+
+/// Starts a new page of the ledger at the start of every month
+fn close_month(clock: Res<ZooClock>, mut ledger: ResMut<ZooLedger>) {
+    if clock.is_new_month() {
+        ledger.last_month = std::mem::take(&mut ledger.this_month);
+    }
+}
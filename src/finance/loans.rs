@@ -0,0 +1,351 @@
+use super::{
+    ledger::LedgerCategory,
+    placed::{PlacedObject, PlacedObjects},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    zoo::{Zoo, ZooBalanceChange, ZooLogMessage},
+    Currency, CurrencyFormat,
+};
+use bevy::prelude::*;
+
+pub struct LoanPlugin;
+impl Plugin for LoanPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ZooLoans>()
+            .init_resource::<Bankruptcy>()
+            .add_event::<TakeLoan>()
+            .add_event::<RepayLoan>()
+            .add_systems(Update, (assess_credit, on_take_loan, on_repay_loan).chain())
+            .add_systems(Simulation, (make_repayments, check_bankruptcy));
+    }
+}
+
+// constants for loans
+/// Fraction of the amount still owed on a loan that is charged as interest each month
+const MONTHLY_INTEREST: f32 = 0.01;
+/// Months that a loan is paid back over
+const LOAN_TERM: u32 = 24;
+/// Credit that every zoo is given, regardless of what it is worth
+const BASE_CREDIT: Currency = 10000.0;
+/// Fraction of the value of the zoo's animals and objects that it can borrow against
+const CREDIT_FRACTION: f32 = 0.5;
+/// Days that the zoo can stay in debt before going bankrupt, unless a scenario says otherwise
+const DEFAULT_GRACE_DAYS: u32 = 90;
+
+/// Money borrowed by the zoo, paid back in monthly installments
+#[derive(Clone, Copy, Debug)]
+pub struct Loan {
+    /// Amount still owed, not including interest yet to be charged
+    pub remaining: Currency,
+    /// Monthly installments left until the loan is paid off
+    pub months_remaining: u32,
+}
+
+impl Loan {
+    /// Portion of the amount owed that is paid back at the next installment
+    fn next_principal(&self) -> Currency {
+        self.remaining / self.months_remaining.max(1) as f32
+    }
+
+    /// Interest charged at the next installment
+    fn next_interest(&self) -> Currency {
+        self.remaining * MONTHLY_INTEREST
+    }
+
+    /// Pays the next installment, returning the principal and interest that were paid
+    fn pay_installment(&mut self) -> (Currency, Currency) {
+        let (principal, interest) = (self.next_principal(), self.next_interest());
+        self.remaining -= principal;
+        self.months_remaining -= 1;
+        (principal, interest)
+    }
+}
+
+/// Every loan the zoo has taken out, along with how much more it is able to borrow
+#[derive(Resource, Default)]
+pub struct ZooLoans {
+    loans: Vec<Loan>,
+    /// Most that the zoo can owe at once, based on what the zoo is worth
+    credit_limit: Currency,
+}
+
+impl ZooLoans {
+    /// Returns the total amount owed across every loan
+    pub fn debt(&self) -> Currency {
+        self.loans.iter().map(|loan| loan.remaining).sum()
+    }
+
+    /// Returns the most that the zoo can currently borrow
+    pub fn available_credit(&self) -> Currency {
+        (self.credit_limit - self.debt()).max(0.0)
+    }
+
+    /// Returns the amount that will be paid at the start of next month, including interest
+    pub fn next_repayment(&self) -> Currency {
+        self.loans
+            .iter()
+            .map(|loan| loan.next_principal() + loan.next_interest())
+            .sum()
+    }
+}
+
+/// Event sent to borrow money, as long as it stays within the zoo's credit limit
+#[derive(Event)]
+pub struct TakeLoan {
+    pub amount: Currency,
+}
+
+/// Event sent to pay back part of the zoo's loans early, starting with the oldest
+#[derive(Event)]
+pub struct RepayLoan {
+    pub amount: Currency,
+}
+
+/// How long the zoo has been in debt, and whether it has gone bankrupt because of it
+#[derive(Resource)]
+pub struct Bankruptcy {
+    /// Days in a row that the zoo can have a negative balance for before going bankrupt
+    pub grace_days: u32,
+    days_in_debt: u32,
+    declared: bool,
+}
+
+impl Default for Bankruptcy {
+    fn default() -> Self {
+        Self {
+            grace_days: DEFAULT_GRACE_DAYS,
+            days_in_debt: 0,
+            declared: false,
+        }
+    }
+}
+
+impl Bankruptcy {
+    /// Returns the days left to get out of debt before going bankrupt, if the zoo is in debt
+    pub fn days_remaining(&self) -> Option<u32> {
+        match self.days_in_debt > 0 {
+            true => Some(self.grace_days.saturating_sub(self.days_in_debt)),
+            false => None,
+        }
+    }
+
+    /// Returns true once the zoo has gone bankrupt, ending the game
+    pub fn is_declared(&self) -> bool {
+        self.declared
+    }
+}
+
+/// Total catalog cost of every animal and placed object
+fn zoo_value(objects: &PlacedObjects) -> Currency {
+    objects.total(|object| match object {
+        PlacedObject::Animal(data) => data.cost,
+        PlacedObject::Post(data) => data.post_cost,
+        PlacedObject::Fence(data, length) => data.fence_cost * length,
+        PlacedObject::Facility(data) => data.cost,
+        PlacedObject::Prop(data) => data.cost,
+    })
+}
+
+/// Most that a zoo worth the given value can owe at once
+fn credit_limit(value: Currency) -> Currency {
+    BASE_CREDIT + value * CREDIT_FRACTION
+}
+
+/// Keeps the zoo's credit limit in line with what the zoo is worth
+fn assess_credit(objects: PlacedObjects, loans: ResMut<ZooLoans>) {
+    loans
+        .map_unchanged(|loans| &mut loans.credit_limit)
+        .set_if_neq(credit_limit(zoo_value(&objects)));
+}
+
+/// Takes out new loans that fit within the zoo's credit limit
+fn on_take_loan(
+    bankruptcy: Res<Bankruptcy>,
+    mut loans: ResMut<ZooLoans>,
+    mut requests: EventReader<TakeLoan>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    for request in requests.iter() {
+        if bankruptcy.is_declared() || request.amount > loans.available_credit() {
+            continue;
+        }
+
+        loans.loans.push(Loan {
+            remaining: request.amount,
+            months_remaining: LOAN_TERM,
+        });
+
+        balance_changes.send(ZooBalanceChange {
+            amount: request.amount,
+            category: LedgerCategory::Loans,
+        });
+        log_messages.send(ZooLogMessage {
+            message: format!(
+                "Borrowed {}, to be paid back over {} months",
+                request.amount.comma_separated(),
+                LOAN_TERM
+            ),
+        });
+    }
+}
+
+/// Pays off loans early if the zoo can afford it, spreading the rest of each loan over its remaining months
+fn on_repay_loan(
+    zoo: Res<Zoo>,
+    mut loans: ResMut<ZooLoans>,
+    mut requests: EventReader<RepayLoan>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    // track spending within this frame, since balance changes are only applied later
+    let mut balance = zoo.balance();
+
+    for request in requests.iter() {
+        let mut amount = request.amount.min(loans.debt()).min(balance);
+        if amount <= 0.0 {
+            continue;
+        }
+
+        balance -= amount;
+        balance_changes.send(ZooBalanceChange {
+            amount: -amount,
+            category: LedgerCategory::Loans,
+        });
+
+        for loan in loans.loans.iter_mut() {
+            let paid = amount.min(loan.remaining);
+            loan.remaining -= paid;
+            amount -= paid;
+        }
+
+        loans.loans.retain(|loan| loan.remaining > 0.0);
+    }
+}
+
+/// Pays the next installment of every loan, along with its interest, at the start of each month
+fn make_repayments(
+    clock: Res<ZooClock>,
+    mut loans: ResMut<ZooLoans>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    if !clock.is_new_month() || loans.loans.is_empty() {
+        return;
+    }
+
+    for loan in loans.loans.iter_mut() {
+        let (principal, interest) = loan.pay_installment();
+        balance_changes.send(ZooBalanceChange {
+            amount: -principal,
+            category: LedgerCategory::Loans,
+        });
+        balance_changes.send(ZooBalanceChange {
+            amount: -interest,
+            category: LedgerCategory::Interest,
+        });
+    }
+
+    loans.loans.retain(|loan| loan.months_remaining > 0);
+}
+
+/// Counts the days that the zoo spends in debt, declaring it bankrupt once it has been for too long
+fn check_bankruptcy(
+    zoo: Res<Zoo>,
+    mut clock: ResMut<ZooClock>,
+    mut bankruptcy: ResMut<Bankruptcy>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    if !clock.is_new_day() || bankruptcy.declared {
+        return;
+    }
+
+    if zoo.balance() >= 0.0 {
+        bankruptcy.days_in_debt = 0;
+        return;
+    }
+
+    bankruptcy.days_in_debt += 1;
+
+    if bankruptcy.days_in_debt == 1 {
+        log_messages.send(ZooLogMessage {
+            message: format!(
+                "The zoo is in debt, and will go bankrupt if it can't recover within {} days",
+                bankruptcy.grace_days
+            ),
+        });
+    }
+
+    if bankruptcy.days_in_debt >= bankruptcy.grace_days {
+        bankruptcy.declared = true;
+        clock.stop();
+
+        log_messages.send(ZooLogMessage {
+            message: "The zoo has gone bankrupt".into(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interest_is_charged_on_the_amount_still_owed() {
+        let mut loan = Loan {
+            remaining: 1000.0,
+            months_remaining: 10,
+        };
+
+        assert_eq!(loan.pay_installment(), (100.0, 10.0));
+        assert_eq!(loan.remaining, 900.0);
+        assert_eq!(loan.next_interest(), 9.0);
+    }
+
+    #[test]
+    fn loans_are_paid_off_by_the_end_of_their_term() {
+        let mut loan = Loan {
+            remaining: 12000.0,
+            months_remaining: LOAN_TERM,
+        };
+
+        let (mut principal, mut interest) = (0.0, 0.0);
+        for _ in 0..LOAN_TERM {
+            let (paid_principal, paid_interest) = loan.pay_installment();
+            principal += paid_principal;
+            interest += paid_interest;
+        }
+
+        // the same principal is paid each month, so interest is charged on an average of half the loan
+        assert_eq!(loan.months_remaining, 0);
+        assert!(loan.remaining.abs() < 0.01);
+        assert!((principal - 12000.0).abs() < 0.01);
+        let expected_interest = 12000.0 * MONTHLY_INTEREST * (LOAN_TERM + 1) as f32 / 2.0;
+        assert!((interest - expected_interest).abs() < 0.01);
+    }
+
+    #[test]
+    fn credit_limit_grows_with_the_value_of_the_zoo() {
+        assert_eq!(credit_limit(0.0), BASE_CREDIT);
+        assert_eq!(
+            credit_limit(10000.0),
+            BASE_CREDIT + 10000.0 * CREDIT_FRACTION
+        );
+    }
+
+    #[test]
+    fn available_credit_is_what_is_left_under_the_limit() {
+        let mut loans = ZooLoans {
+            loans: vec![Loan {
+                remaining: 3000.0,
+                months_remaining: LOAN_TERM,
+            }],
+            credit_limit: 10000.0,
+        };
+        assert_eq!(loans.available_credit(), 7000.0);
+        assert_eq!(loans.next_repayment(), 3000.0 / LOAN_TERM as f32 + 30.0);
+
+        // zoos that lose value can end up owing more than their limit, but never have negative credit
+        loans.credit_limit = 2000.0;
+        assert_eq!(loans.available_credit(), 0.0);
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+mod ledger;
+mod loans;
+mod placed;
+mod upkeep;
+
+pub use ledger::{LedgerCategory, ZooLedger};
+pub use loans::{Bankruptcy, RepayLoan, TakeLoan, ZooLoans};
+
+pub struct FinancePlugin;
+impl Plugin for FinancePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ledger::LedgerPlugin,
+            loans::LoanPlugin,
+            upkeep::UpkeepPlugin,
+        ));
+    }
+}
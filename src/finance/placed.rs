@@ -0,0 +1,76 @@
+use crate::{
+    objects::{
+        Animal, AnimalData, BarrierData, BarrierFence, BarrierPost, Facility, FacilityData, Prop,
+        PropData,
+    },
+    placement::Preview,
+    Currency,
+};
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/// Catalog entry of an animal or object that has been placed in the zoo
+pub(super) enum PlacedObject<'a> {
+    Animal(&'a AnimalData),
+    Post(&'a BarrierData),
+    /// Fence along with its length in meters
+    Fence(&'a BarrierData, f32),
+    Facility(&'a FacilityData),
+    Prop(&'a PropData),
+}
+
+/// Utility system parameter for adding up prices over every animal and object placed in the zoo
+#[derive(SystemParam)]
+pub(super) struct PlacedObjects<'w, 's> {
+    animal_data: Res<'w, Assets<AnimalData>>,
+    barrier_data: Res<'w, Assets<BarrierData>>,
+    facility_data: Res<'w, Assets<FacilityData>>,
+    prop_data: Res<'w, Assets<PropData>>,
+    animals: Query<'w, 's, &'static Animal, Without<Preview>>,
+    posts: Query<'w, 's, &'static BarrierPost, Without<Preview>>,
+    fences: Query<'w, 's, (&'static BarrierFence, &'static Transform), Without<Preview>>,
+    facilities: Query<'w, 's, &'static Facility, Without<Preview>>,
+    props: Query<'w, 's, &'static Prop, Without<Preview>>,
+}
+
+impl<'w, 's> PlacedObjects<'w, 's> {
+    /// Sum of the given price over every placed animal and object
+    pub fn total(&self, price: impl Fn(PlacedObject) -> Currency) -> Currency {
+        let animals = self
+            .animals
+            .iter()
+            .filter_map(|animal| self.animal_data.get(&animal.data))
+            .map(PlacedObject::Animal);
+
+        let posts = self
+            .posts
+            .iter()
+            .filter_map(|post| self.barrier_data.get(&post.data))
+            .map(PlacedObject::Post);
+
+        // fences are stretched along their x axis to their length in meters
+        let fences = self.fences.iter().filter_map(|(fence, transform)| {
+            let data = self.barrier_data.get(&fence.data)?;
+            Some(PlacedObject::Fence(data, transform.scale.x))
+        });
+
+        let facilities = self
+            .facilities
+            .iter()
+            .filter_map(|facility| self.facility_data.get(&facility.data))
+            .map(PlacedObject::Facility);
+
+        let props = self
+            .props
+            .iter()
+            .filter_map(|prop| self.prop_data.get(&prop.data))
+            .map(PlacedObject::Prop);
+
+        animals
+            .chain(posts)
+            .chain(fences)
+            .chain(facilities)
+            .chain(props)
+            .map(price)
+            .sum()
+    }
+}
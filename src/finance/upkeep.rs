@@ -0,0 +1,61 @@
+use super::{
+    ledger::{LedgerCategory, ZooLedger},
+    placed::{PlacedObject, PlacedObjects},
+};
+use crate::{
+    clock::{Simulation, ZooClock, DAYS_PER_MONTH},
+    staff::Staff,
+    zoo::ZooBalanceChange,
+    Currency,
+};
+use bevy::prelude::*;
+
+pub struct UpkeepPlugin;
+impl Plugin for UpkeepPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, project_expenses)
+            .add_systems(Simulation, charge_upkeep);
+    }
+}
+
+/// Total upkeep of every animal and placed object, as defined by their catalog entries
+fn daily_upkeep(objects: &PlacedObjects) -> Currency {
+    objects.total(|object| match object {
+        PlacedObject::Animal(data) => data.upkeep,
+        PlacedObject::Post(_) => 0.0,
+        PlacedObject::Fence(data, length) => data.fence_upkeep * length,
+        PlacedObject::Facility(data) => data.upkeep,
+        PlacedObject::Prop(data) => data.upkeep,
+    })
+}
+
+/// Keeps the ledger's projection of monthly expenses up to date as the zoo changes, even while paused
+fn project_expenses(objects: PlacedObjects, staff: Query<&Staff>, ledger: ResMut<ZooLedger>) {
+    let wages = staff
+        .iter()
+        .map(|member| member.role.wage())
+        .sum::<Currency>();
+
+    ledger
+        .map_unchanged(|ledger| &mut ledger.projected_expenses)
+        .set_if_neq(daily_upkeep(&objects) * DAYS_PER_MONTH as f32 + wages);
+}
+
+/// Charges the running costs of the zoo at the start of each day
+fn charge_upkeep(
+    clock: Res<ZooClock>,
+    objects: PlacedObjects,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    if !clock.is_new_day() {
+        return;
+    }
+
+    let upkeep = daily_upkeep(&objects);
+    if upkeep > 0.0 {
+        balance_changes.send(ZooBalanceChange {
+            amount: -upkeep,
+            category: LedgerCategory::Upkeep,
+        });
+    }
+}
@@ -0,0 +1,190 @@
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet};
+
+/// Graph of nodes connected by edges that can be traversed in either direction
+pub trait UndirectedGraph {
+    /// Common type that the graph nodes are stored as
+    type Node: Copy + Eq + Hash;
+
+    /// Return all neighboring nodes from the given node
+    fn neighbors(&self, node: Self::Node) -> Vec<Self::Node>;
+
+    /// Return the cost of traveling between two neighboring nodes
+    fn edge_cost(&self, _from: Self::Node, _to: Self::Node) -> f32 {
+        1.0
+    }
+
+    /// Finds the cheapest route between two nodes using Dijkstra's algorithm
+    ///
+    /// Returns either `Some` list of nodes from the start to the goal, including both, or `None` if they aren't connected
+    fn shortest_path(&self, start: Self::Node, goal: Self::Node) -> Option<Vec<Self::Node>> {
+        // best known cost to reach each node, along with the node it was reached from
+        let mut costs: HashMap<Self::Node, (f32, Self::Node)> =
+            HashMap::from([(start, (0.0, start))]);
+        let mut frontier = vec![start];
+        let mut settled = HashSet::new();
+
+        while !frontier.is_empty() {
+            // graphs are small enough that scanning for the cheapest frontier node is fine
+            let cheapest = (0..frontier.len())
+                .min_by(|&a, &b| costs[&frontier[a]].0.total_cmp(&costs[&frontier[b]].0))?;
+            let current = frontier.swap_remove(cheapest);
+
+            if current == goal {
+                break;
+            }
+
+            settled.insert(current);
+            let current_cost = costs[&current].0;
+
+            for neighbor in self.neighbors(current) {
+                if settled.contains(&neighbor) {
+                    continue;
+                }
+
+                let cost = current_cost + self.edge_cost(current, neighbor);
+                match costs.get(&neighbor) {
+                    Some(&(known_cost, _)) if known_cost <= cost => (),
+                    Some(_) => {
+                        costs.insert(neighbor, (cost, current));
+                    }
+                    None => {
+                        costs.insert(neighbor, (cost, current));
+                        frontier.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        // retrace steps from the goal back to the start
+        costs.get(&goal)?;
+
+        let mut nodes = vec![goal];
+        let mut current = goal;
+        while current != start {
+            current = costs[&current].1;
+            nodes.push(current);
+        }
+
+        nodes.reverse();
+        Some(nodes)
+    }
+
+    /// Searches from the given root node to detect if a cycle occurs in the graph
+    ///
+    /// Returns either `Some` list containing all nodes in the cycle or `None` if there is no cycle
+    fn get_cycle(&self, root: Self::Node) -> Option<Vec<Self::Node>> {
+        let mut visited = HashMap::new();
+
+        // run dfs on the graph and if a cycle is found, collect all nodes in it
+        self.dfs(root, None, &mut visited).map(|end| {
+            let mut nodes = vec![end];
+            let mut current = end;
+
+            // retrace steps from the end node back to the root node
+            while current != root {
+                let parent = visited[&current];
+
+                nodes.push(parent);
+                current = parent;
+            }
+
+            nodes
+        })
+    }
+
+    /// Recursively searches through the graph, marking nodes as visited
+    ///
+    /// If a cycle is found, the ending node is propogated back to the initial caller so that the path can be retraced
+    fn dfs(
+        &self,
+        current: Self::Node,
+        parent: Option<Self::Node>,
+        visited: &mut HashMap<Self::Node, Self::Node>,
+    ) -> Option<Self::Node> {
+        visited.insert(current, parent.unwrap_or(current));
+
+        for neighbor in self.neighbors(current) {
+            if Some(neighbor) == parent {
+                continue;
+            }
+
+            if visited.contains_key(&neighbor) {
+                return Some(current);
+            } else if let Some(end) = self.dfs(neighbor, Some(current), visited) {
+                return Some(end);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small weighted graph stored as a list of edges between numbered nodes
+    struct TestGraph {
+        edges: Vec<(u32, u32, f32)>,
+    }
+
+    impl UndirectedGraph for TestGraph {
+        type Node = u32;
+
+        fn neighbors(&self, node: u32) -> Vec<u32> {
+            self.edges
+                .iter()
+                .filter_map(|&(a, b, _)| match (a == node, b == node) {
+                    (true, _) => Some(b),
+                    (_, true) => Some(a),
+                    _ => None,
+                })
+                .collect()
+        }
+
+        fn edge_cost(&self, from: u32, to: u32) -> f32 {
+            self.edges
+                .iter()
+                .find(|&&(a, b, _)| (a, b) == (from, to) || (b, a) == (from, to))
+                .map_or(f32::INFINITY, |&(_, _, cost)| cost)
+        }
+    }
+
+    /// Square of four nodes where the long way around is cheaper than the direct edge, plus a node with no edges
+    fn square() -> TestGraph {
+        TestGraph {
+            edges: vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0), (3, 0, 5.0)],
+        }
+    }
+
+    #[test]
+    fn shortest_path_follows_the_cheapest_edges() {
+        assert_eq!(square().shortest_path(0, 3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(square().shortest_path(3, 1), Some(vec![3, 2, 1]));
+    }
+
+    #[test]
+    fn shortest_path_to_the_start_is_just_the_start() {
+        assert_eq!(square().shortest_path(2, 2), Some(vec![2]));
+    }
+
+    #[test]
+    fn shortest_path_to_an_unreachable_node_is_none() {
+        assert_eq!(square().shortest_path(0, 4), None);
+        assert_eq!(square().shortest_path(4, 0), None);
+    }
+
+    #[test]
+    fn cycles_are_found_from_any_of_their_nodes() {
+        let cycle = square()
+            .get_cycle(1)
+            .expect("square should contain a cycle");
+        assert_eq!(cycle.len(), 4);
+
+        let line = TestGraph {
+            edges: vec![(0, 1, 1.0), (1, 2, 1.0)],
+        };
+        assert_eq!(line.get_cycle(0), None);
+    }
+}
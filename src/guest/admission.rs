@@ -0,0 +1,139 @@
+use crate::{
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    zoo::{Zoo, ZooBalanceChange, ZooLogMessage},
+    Currency, CurrencyFormat,
+};
+use bevy::prelude::*;
+
+pub struct GuestAdmissionPlugin;
+impl Plugin for GuestAdmissionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Admission>()
+            .add_event::<ChangeTicketPrice>()
+            .add_event::<StartMarketingCampaign>()
+            .add_systems(
+                Update,
+                (handle_ticket_price_change, handle_marketing_campaign),
+            )
+            .add_systems(Simulation, update_marketing);
+    }
+}
+
+// constants for admission pricing
+const INITIAL_TICKET_PRICE: Currency = 20.0;
+const MAX_TICKET_PRICE: Currency = 200.0;
+/// Guests arriving each second at an average reputation with a fairly priced ticket
+const BASE_ARRIVAL_RATE: f32 = 0.2;
+/// Ticket price that guests consider fair at the lowest reputation
+const BASE_FAIR_PRICE: Currency = 10.0;
+/// Additional price guests are willing to pay for each point of reputation
+const FAIR_PRICE_PER_REPUTATION: Currency = 0.4;
+
+// constants for marketing
+const MARKETING_COST: Currency = 1000.0;
+/// Days that a marketing campaign runs for
+const MARKETING_DAYS: u32 = 30;
+/// Additional fraction of guests arriving while a marketing campaign is running
+const MARKETING_BOOST: f32 = 0.5;
+
+/// Pricing of the zoo's entrance and promotion of the zoo to potential guests
+#[derive(Resource)]
+pub struct Admission {
+    pub ticket_price: Currency,
+    /// Days remaining in the current marketing campaign
+    marketing_remaining: u32,
+}
+
+impl Default for Admission {
+    fn default() -> Self {
+        Self {
+            ticket_price: INITIAL_TICKET_PRICE,
+            marketing_remaining: 0,
+        }
+    }
+}
+
+impl Admission {
+    /// Returns true while a marketing campaign is running
+    pub fn is_marketing(&self) -> bool {
+        self.marketing_remaining > 0
+    }
+
+    /// Ticket price that guests consider fair for a zoo of the given reputation
+    pub fn fair_price(reputation: f32) -> Currency {
+        BASE_FAIR_PRICE + FAIR_PRICE_PER_REPUTATION * reputation
+    }
+
+    /// Average number of guests arriving each second, based on the zoo's reputation, pricing and marketing
+    pub fn arrival_rate(&self, reputation: f32) -> f32 {
+        // reputation ranges from 0 to 100, with an average zoo at 50
+        let reputation_factor = 0.25 + 1.5 * reputation / 100.0;
+
+        // cheaper tickets draw more guests, while overpriced tickets quickly drive them away
+        let fair_price = Self::fair_price(reputation);
+        let price_factor = (2.0 - self.ticket_price / fair_price).clamp(0.05, 1.5);
+
+        let marketing_factor = match self.is_marketing() {
+            true => 1.0 + MARKETING_BOOST,
+            false => 1.0,
+        };
+
+        BASE_ARRIVAL_RATE * reputation_factor * price_factor * marketing_factor
+    }
+}
+
+/// Event to be called to adjust the ticket price by some amount
+#[derive(Event)]
+pub struct ChangeTicketPrice {
+    pub amount: Currency,
+}
+
+/// Event to be called to pay for a marketing campaign
+#[derive(Event)]
+pub struct StartMarketingCampaign;
+
+fn handle_ticket_price_change(
+    mut admission: ResMut<Admission>,
+    mut price_changes: EventReader<ChangeTicketPrice>,
+) {
+    for price_change in price_changes.iter() {
+        admission.ticket_price =
+            (admission.ticket_price + price_change.amount).clamp(0.0, MAX_TICKET_PRICE);
+    }
+}
+
+fn handle_marketing_campaign(
+    zoo: Res<Zoo>,
+    mut admission: ResMut<Admission>,
+    mut campaigns: EventReader<StartMarketingCampaign>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    for _ in campaigns.iter() {
+        if admission.is_marketing() || zoo.balance() < MARKETING_COST {
+            continue;
+        }
+
+        admission.marketing_remaining = MARKETING_DAYS;
+
+        balance_changes.send(ZooBalanceChange {
+            amount: -MARKETING_COST,
+            category: LedgerCategory::Marketing,
+        });
+        log_messages.send(ZooLogMessage {
+            message: format!(
+                "Started a marketing campaign for {}",
+                MARKETING_COST.comma_separated()
+            ),
+        });
+    }
+}
+
+/// Counts down the current marketing campaign at the start of each day
+fn update_marketing(clock: Res<ZooClock>, mut admission: ResMut<Admission>) {
+    // avoid triggering change detection when there is no campaign running
+    if clock.is_new_day() && admission.is_marketing() {
+        admission.marketing_remaining -= 1;
+    }
+}
@@ -0,0 +1,63 @@
+use super::{
+    behavior::{Guest, GuestActivity},
+    needs::GuestNeeds,
+    thoughts::{GuestThought, GuestThoughts},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::Escaped,
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct GuestAlarmPlugin;
+impl Plugin for GuestAlarmPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, flee_escaped_animals);
+    }
+}
+
+// constants for guests reacting to escaped animals
+/// Seconds between guests looking around for escaped animals
+const ALARM_INTERVAL: f32 = 1.0;
+/// Distance that guests notice an escaped animal from
+const ALARM_RANGE: f32 = 10.0;
+/// Fun lost by a guest frightened by an escaped animal
+const FRIGHT_PENALTY: f32 = 0.3;
+
+/// Sends guests that spot an escaped animal running for the exit, frightened and a lot less happy
+fn flee_escaped_animals(
+    clock: Res<ZooClock>,
+    escaped: Query<&Transform, With<Escaped>>,
+    mut guests: Query<(&mut Guest, &mut GuestNeeds, &mut GuestThoughts, &Transform)>,
+    mut since_check: Local<f32>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < ALARM_INTERVAL {
+        return;
+    }
+    *since_check = 0.0;
+
+    let animals = escaped
+        .iter()
+        .map(|transform| transform.translation.xz())
+        .collect::<Vec<_>>();
+
+    for (mut guest, mut needs, mut thoughts, transform) in guests.iter_mut() {
+        // guests already on their way out are only frightened once
+        if guest.activity == GuestActivity::Leave {
+            continue;
+        }
+
+        let position = transform.translation.xz();
+        if !animals
+            .iter()
+            .any(|animal| animal.distance(position) <= ALARM_RANGE)
+        {
+            continue;
+        }
+
+        needs.fun = (needs.fun - FRIGHT_PENALTY).max(0.0);
+        thoughts.think(GuestThought::AnimalLoose);
+        guest.activity = GuestActivity::Leave;
+    }
+}
@@ -0,0 +1,123 @@
+use super::{
+    admission::Admission, behavior::Guest, needs::GuestNeeds, thoughts::GuestThoughts,
+    viewing::GuestViewing,
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    rating::ZooRating,
+    rng::SeededRng,
+    terrain::Terrain,
+    zoo::{Zoo, ZooBalanceChange},
+    Currency,
+};
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+pub struct GuestArrivalPlugin;
+impl Plugin for GuestArrivalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ZooEntrance>()
+            .init_resource::<GuestRecords>()
+            .add_systems(Simulation, spawn_guests);
+    }
+}
+
+// constants for guest arrival
+/// Distance around the entrance that guests arrive within
+const ARRIVAL_SPREAD: f32 = 1.0;
+
+/// Point where guests arrive at and leave from the zoo
+#[derive(Resource)]
+pub struct ZooEntrance {
+    pub position: Vec2,
+}
+
+impl Default for ZooEntrance {
+    fn default() -> Self {
+        // edge of the zoo's starting land, where the entrance gate starts
+        Self {
+            position: Vec2::new(0.0, -15.0),
+        }
+    }
+}
+
+/// Running totals of guests visiting the zoo
+#[derive(Resource, Default)]
+pub struct GuestRecords {
+    pub arrived: usize,
+    pub departed: usize,
+    /// Guests that couldn't afford a ticket
+    pub turned_away: usize,
+    /// Total money earned from selling tickets
+    pub ticket_income: Currency,
+}
+
+/// System parameter for letting new guests into the zoo through its entrance, as long as they can pay admission
+#[derive(SystemParam)]
+struct GuestArrivals<'w> {
+    zoo: Res<'w, Zoo>,
+    entrance: Res<'w, ZooEntrance>,
+    admission: Res<'w, Admission>,
+    rating: Res<'w, ZooRating>,
+    records: ResMut<'w, GuestRecords>,
+    rng: ResMut<'w, SeededRng>,
+}
+
+/// Spawns new guests at the zoo's entrance as they arrive, charging each of them admission
+fn spawn_guests(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    terrain: Res<Terrain>,
+    mut arrivals: GuestArrivals,
+    guests: Query<(), With<Guest>>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+    mut pending_guests: Local<f32>,
+) {
+    // fractions of a guest build up until there is a whole guest to arrive
+    *pending_guests +=
+        arrivals.admission.arrival_rate(arrivals.zoo.reputation()) * clock.delta_seconds();
+    if *pending_guests < 1.0 {
+        return;
+    }
+    *pending_guests -= 1.0;
+
+    // the zoo's rating limits how many guests it can draw in at once
+    if guests.iter().count() >= arrivals.rating.guest_capacity() {
+        return;
+    }
+
+    let mut guest_rng = arrivals.rng.fork();
+    let mut needs = GuestNeeds::arriving(&mut guest_rng);
+
+    // guests who can't afford a ticket go home straight away
+    let ticket_price = arrivals.admission.ticket_price;
+    if needs.money < ticket_price {
+        arrivals.records.turned_away += 1;
+        return;
+    }
+
+    let offset = Vec2::new(
+        guest_rng.range(-ARRIVAL_SPREAD..ARRIVAL_SPREAD),
+        guest_rng.range(-ARRIVAL_SPREAD..ARRIVAL_SPREAD),
+    );
+    let position = arrivals.entrance.position + offset;
+    let Some(height) = terrain.height_at(position) else { return };
+
+    needs.money -= ticket_price;
+    arrivals.records.ticket_income += ticket_price;
+    balance_changes.send(ZooBalanceChange {
+        amount: ticket_price,
+        category: LedgerCategory::Admissions,
+    });
+
+    commands.spawn((
+        needs,
+        GuestThoughts::default(),
+        GuestViewing::default(),
+        Guest::new(guest_rng),
+        SpatialBundle::from_transform(Transform::from_xyz(position.x, height, position.y)),
+        Name::new("Guest"),
+    ));
+
+    arrivals.records.arrived += 1;
+}
@@ -0,0 +1,457 @@
+use super::{
+    arrival::{GuestRecords, ZooEntrance},
+    facilities::{seek_facility, FacilitySummary},
+    needs::{GuestNeed, GuestNeeds},
+    thoughts::{GuestThought, GuestThoughts},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    land::Land,
+    objects::{Enclosure, Facility, FacilityData, PathGraph},
+    placement::Preview,
+    rng::SeededRng,
+    terrain::{SurfaceType, Terrain, WaterMobility},
+    Currency,
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+use std::collections::VecDeque;
+
+pub struct GuestBehaviorPlugin;
+impl Plugin for GuestBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, update_behavior);
+    }
+}
+
+// constants for guest behavior
+/// Walking speed of guests in meters per second
+const WALK_SPEED: f32 = 1.4;
+/// Distance from a destination that a guest is considered to have arrived at it
+const ARRIVAL_DISTANCE: f32 = 0.25;
+/// Distance outside of an enclosure's boundary that guests stand to look in
+const VIEWING_DISTANCE: f32 = 1.5;
+/// Number of random points tried when looking for somewhere to wander
+const DESTINATION_ATTEMPTS: usize = 16;
+/// Range of seconds that a guest stands around for
+const IDLE_DURATION: std::ops::Range<f32> = 1.0..4.0;
+/// Range of seconds that a guest rests for
+const REST_DURATION: std::ops::Range<f32> = 10.0..20.0;
+/// Range of seconds that a guest spends looking at an enclosure
+const VIEWING_DURATION: std::ops::Range<f32> = 8.0..20.0;
+/// Depth of water that guests are willing to walk through
+const MAX_WADING_DEPTH: f32 = 0.1;
+/// Least money a guest needs left before considering buying a souvenir
+const SHOPPING_MONEY: Currency = 30.0;
+/// Chance that a guest with money to spare goes looking for a souvenir
+const SHOPPING_CHANCE: f32 = 0.1;
+
+/// What a guest is currently doing
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GuestActivity {
+    /// Standing in place for the remaining number of seconds
+    Idle { remaining: f32 },
+    /// Resting for the remaining number of seconds to recover energy
+    Rest { remaining: f32 },
+    /// Walking towards a random destination within the zoo
+    Wander { destination: Vec2 },
+    /// Walking towards a point to look into an enclosure from
+    Visit {
+        destination: Vec2,
+        enclosure: Entity,
+    },
+    /// Looking into an enclosure for the remaining number of seconds
+    View { remaining: f32, enclosure: Entity },
+    /// Walking towards a facility to satisfy a need
+    Seek { destination: Vec2, facility: Entity },
+    /// Waiting in line or being served at a facility, until the facility is done with them
+    Queue { facility: Entity },
+    /// Walking back to the entrance to leave the zoo
+    Leave,
+}
+
+/// Everything in the zoo that a guest considers when choosing what to do next
+struct Surroundings<'a> {
+    enclosures: Vec<(Entity, &'a Enclosure)>,
+    facilities: Vec<FacilitySummary>,
+    path_points: Vec<Vec2>,
+}
+
+/// Walk planned along the paths towards a destination
+struct GuestRoute {
+    destination: Vec2,
+    waypoints: VecDeque<Vec2>,
+}
+
+/// Visitor walking around the zoo until their needs send them home
+#[derive(Component)]
+pub struct Guest {
+    pub activity: GuestActivity,
+    /// Points along the paths leading to the guest's current destination
+    route: Option<GuestRoute>,
+    /// Each guest has its own generator so that its decisions don't depend on the order guests are updated in
+    pub(super) rng: SeededRng,
+}
+
+impl Guest {
+    pub fn new(rng: SeededRng) -> Self {
+        Self {
+            activity: GuestActivity::Idle { remaining: 0.0 },
+            route: None,
+            rng,
+        }
+    }
+
+    /// Picks the next activity once the current one has finished, based on what the guest needs most
+    fn choose_activity(
+        &mut self,
+        position: Vec2,
+        needs: &GuestNeeds,
+        thoughts: &mut GuestThoughts,
+        surroundings: &Surroundings,
+        accept: impl Fn(Vec2) -> bool,
+    ) -> GuestActivity {
+        if needs.wants_to_leave() {
+            return GuestActivity::Leave;
+        }
+
+        if let Some(need) = needs.most_urgent() {
+            match seek_facility(position, need, needs.money, &surroundings.facilities) {
+                Ok(activity) => return activity,
+                Err(thought) => thoughts.think(thought),
+            }
+
+            // tired guests rest wherever they are if there is nowhere to sit
+            if need == GuestNeed::Energy {
+                return self.rest();
+            }
+        }
+
+        // guests with money to spare sometimes look for a souvenir
+        if needs.money >= SHOPPING_MONEY && self.rng.chance(SHOPPING_CHANCE) {
+            let found = seek_facility(
+                position,
+                GuestNeed::Fun,
+                needs.money,
+                &surroundings.facilities,
+            );
+            if let Ok(activity) = found {
+                return activity;
+            }
+        }
+
+        let enclosures = &surroundings.enclosures;
+
+        if enclosures.is_empty() {
+            thoughts.think(GuestThought::CouldntFind(GuestNeed::Fun));
+        }
+
+        // bored guests are more likely to go and look at animals
+        if self.rng.chance(0.4 + 0.5 * (1.0 - needs.fun)) {
+            if let Some(&(entity, enclosure)) = self.rng.pick(enclosures) {
+                if let Some(destination) = self.viewing_point(enclosure) {
+                    if accept(destination) {
+                        return GuestActivity::Visit {
+                            destination,
+                            enclosure: entity,
+                        };
+                    }
+                }
+            }
+        }
+
+        if self.rng.chance(0.5) {
+            // wander along the paths if there are any, otherwise anywhere nearby
+            let destination = match self.rng.pick(&surroundings.path_points) {
+                Some(&point) => Some(point),
+                None => self.random_point(position, enclosures, &accept),
+            };
+
+            if let Some(destination) = destination {
+                return GuestActivity::Wander { destination };
+            }
+        }
+
+        self.idle()
+    }
+
+    /// Activity for standing around for a random amount of time
+    fn idle(&mut self) -> GuestActivity {
+        GuestActivity::Idle {
+            remaining: self.rng.range(IDLE_DURATION),
+        }
+    }
+
+    /// Activity for resting in place for a random amount of time
+    fn rest(&mut self) -> GuestActivity {
+        GuestActivity::Rest {
+            remaining: self.rng.range(REST_DURATION),
+        }
+    }
+
+    /// Point just outside of a random corner of the enclosure, where the guest can look in from
+    fn viewing_point(&mut self, enclosure: &Enclosure) -> Option<Vec2> {
+        let points = &enclosure.polygon.points;
+        let corner = *self.rng.pick(points)?;
+        let center = points.iter().sum::<Vec2>() / points.len() as f32;
+
+        Some(corner + (corner - center).normalize_or_zero() * VIEWING_DISTANCE)
+    }
+
+    /// Searches for a random point near the guest that is outside of every enclosure
+    fn random_point(
+        &mut self,
+        position: Vec2,
+        enclosures: &[(Entity, &Enclosure)],
+        accept: impl Fn(Vec2) -> bool,
+    ) -> Option<Vec2> {
+        const WANDER_RADIUS: f32 = 12.0;
+
+        (0..DESTINATION_ATTEMPTS)
+            .map(|_| {
+                position
+                    + Vec2::new(
+                        self.rng.range(-WANDER_RADIUS..WANDER_RADIUS),
+                        self.rng.range(-WANDER_RADIUS..WANDER_RADIUS),
+                    )
+            })
+            .find(|&point| {
+                accept(point)
+                    && !enclosures
+                        .iter()
+                        .any(|(_, enclosure)| enclosure.contains(point))
+            })
+    }
+}
+
+/// System parameter for the ground and paths that guests walk over
+#[derive(SystemParam)]
+struct Walkways<'w, 's> {
+    terrain: Res<'w, Terrain>,
+    land: Res<'w, Land>,
+    paths: PathGraph<'w, 's>,
+}
+
+impl Walkways<'_, '_> {
+    /// Returns true if guests can walk over the given point, keeping them within the zoo and out of deep water
+    fn is_walkable(&self, point: Vec2) -> bool {
+        self.land.is_owned(point)
+            && self.terrain.is_passable(
+                point,
+                WaterMobility::Wading {
+                    max_depth: MAX_WADING_DEPTH,
+                },
+            )
+    }
+
+    /// Fraction of their usual walking speed that guests keep at the given point, as rougher ground slows them down
+    fn pace(&self, point: Vec2) -> f32 {
+        match self.terrain.surface_at(point) {
+            Some(SurfaceType::Grass | SurfaceType::Dirt) | None => 1.0,
+            Some(SurfaceType::Sand) => 0.8,
+            Some(SurfaceType::Snow) => 0.7,
+            Some(SurfaceType::Rock) => 0.6,
+        }
+    }
+}
+
+/// Placed facilities along with where they stand
+type FacilitySites<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static Facility, &'static Transform),
+    (Without<Guest>, Without<Preview>),
+>;
+
+/// System parameter for everything in the zoo that guests can go and visit
+#[derive(SystemParam)]
+struct Attractions<'w, 's> {
+    facility_data: Res<'w, Assets<FacilityData>>,
+    enclosures: Query<'w, 's, (Entity, &'static Enclosure)>,
+    facilities: FacilitySites<'w, 's>,
+}
+
+impl Attractions<'_, '_> {
+    /// Gathers what guests can see and use this frame, along with the points on the paths they can wander to
+    fn survey(&self, paths: &PathGraph) -> Surroundings<'_> {
+        Surroundings {
+            enclosures: self.enclosures.iter().collect(),
+            facilities: self
+                .facilities
+                .iter()
+                .filter_map(|(entity, facility, transform)| {
+                    let data = self.facility_data.get(&facility.data)?;
+                    Some(FacilitySummary::new(
+                        entity,
+                        transform.translation.xz(),
+                        facility,
+                        data,
+                    ))
+                })
+                .collect(),
+            path_points: paths.nodes().map(|(_, point)| point).collect(),
+        }
+    }
+}
+
+/// Advances each guest's current activity, moving them along the paths and removing those that have left
+fn update_behavior(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    walkways: Walkways,
+    entrance: Res<ZooEntrance>,
+    mut records: ResMut<GuestRecords>,
+    attractions: Attractions,
+    mut guests: Query<(
+        Entity,
+        &mut Guest,
+        &GuestNeeds,
+        &mut GuestThoughts,
+        &mut Transform,
+    )>,
+) {
+    let delta = clock.delta_seconds();
+    let surroundings = attractions.survey(&walkways.paths);
+
+    let is_walkable = |point| walkways.is_walkable(point);
+
+    for (entity, mut guest, needs, mut thoughts, mut transform) in guests.iter_mut() {
+        let position = transform.translation.xz();
+
+        let destination = match guest.activity {
+            GuestActivity::Idle { ref mut remaining }
+            | GuestActivity::Rest { ref mut remaining } => {
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    guest.activity = guest.choose_activity(
+                        position,
+                        needs,
+                        &mut thoughts,
+                        &surroundings,
+                        is_walkable,
+                    );
+                }
+
+                continue;
+            }
+
+            GuestActivity::View {
+                ref mut remaining,
+                enclosure,
+            } => {
+                *remaining -= delta;
+
+                // enclosures that have since been removed are no longer worth looking at
+                let removed = !surroundings
+                    .enclosures
+                    .iter()
+                    .any(|&(entity, _)| entity == enclosure);
+
+                if *remaining <= 0.0 || removed || needs.wants_to_leave() {
+                    guest.activity = guest.choose_activity(
+                        position,
+                        needs,
+                        &mut thoughts,
+                        &surroundings,
+                        is_walkable,
+                    );
+                }
+
+                continue;
+            }
+
+            GuestActivity::Queue { facility } => {
+                // the facility moves guests along the queue, unless it has since been removed
+                let removed = !surroundings
+                    .facilities
+                    .iter()
+                    .any(|summary| summary.entity == facility);
+
+                if removed || needs.wants_to_leave() {
+                    guest.activity = guest.choose_activity(
+                        position,
+                        needs,
+                        &mut thoughts,
+                        &surroundings,
+                        is_walkable,
+                    );
+                }
+
+                continue;
+            }
+
+            GuestActivity::Wander { destination }
+            | GuestActivity::Visit { destination, .. }
+            | GuestActivity::Seek { destination, .. } => {
+                // tired guests head home instead of walking to their next destination
+                match needs.wants_to_leave() {
+                    true => {
+                        guest.activity = GuestActivity::Leave;
+                        entrance.position
+                    }
+                    false => destination,
+                }
+            }
+
+            GuestActivity::Leave => entrance.position,
+        };
+
+        if position.distance(destination) <= ARRIVAL_DISTANCE {
+            guest.activity = match guest.activity {
+                GuestActivity::Visit { enclosure, .. } => GuestActivity::View {
+                    remaining: guest.rng.range(VIEWING_DURATION),
+                    enclosure,
+                },
+
+                GuestActivity::Seek { facility, .. } => GuestActivity::Queue { facility },
+
+                GuestActivity::Leave => {
+                    records.departed += 1;
+                    commands.entity(entity).despawn_recursive();
+                    continue;
+                }
+
+                _ => guest.idle(),
+            };
+
+            continue;
+        }
+
+        // plan a new route along the paths whenever the destination changes
+        if guest.route.as_ref().map(|route| route.destination) != Some(destination) {
+            guest.route = Some(GuestRoute {
+                destination,
+                waypoints: walkways
+                    .paths
+                    .route(position, destination)
+                    .unwrap_or_default()
+                    .into(),
+            });
+        }
+
+        // walk towards the next waypoint, or straight to the destination without any paths to follow
+        let Some(route) = guest.route.as_mut() else { continue };
+        if route.waypoints.len() > 1
+            && route
+                .waypoints
+                .front()
+                .is_some_and(|&waypoint| position.distance(waypoint) <= ARRIVAL_DISTANCE)
+        {
+            route.waypoints.pop_front();
+        }
+        let target = route.waypoints.front().copied().unwrap_or(destination);
+
+        let step =
+            (target - position).clamp_length_max(WALK_SPEED * walkways.pace(position) * delta);
+        let next = position + step;
+
+        // guests give up on destinations they can't walk to, but can always find their way out
+        let Some(height) = walkways.terrain.height_at(next) else { continue };
+        if guest.activity != GuestActivity::Leave && !is_walkable(next) {
+            guest.activity = guest.idle();
+            continue;
+        }
+
+        transform.translation = Vec3::new(next.x, height, next.y);
+        transform.rotation = Quat::from_rotation_y(-f32::atan2(step.y, step.x));
+    }
+}
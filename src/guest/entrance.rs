@@ -0,0 +1,159 @@
+use super::arrival::ZooEntrance;
+use crate::{
+    camera::CursorRaycast,
+    finance::LedgerCategory,
+    land::Land,
+    placement::{PlacePreview, Preview, PreviewData},
+    terrain::{SeatOnTerrain, Terrain},
+    ui::UiDisplay,
+    zoo::{Zoo, ZooBalanceChange},
+    Currency, CurrencyFormat,
+};
+use bevy::{math::Vec3Swizzles, pbr::NotShadowCaster, prelude::*};
+
+pub struct GuestEntrancePlugin;
+impl Plugin for GuestEntrancePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EntranceGateTool>()
+            .add_systems(Startup, spawn_entrance_gate)
+            .add_systems(Update, (handle_preview_movement, on_preview_place));
+    }
+}
+
+/// Cost of moving the entrance gate somewhere else
+const GATE_COST: Currency = 500.0;
+
+/// Marker component for the gate that guests enter and leave the zoo through
+#[derive(Component)]
+pub struct EntranceGate;
+
+/// Tool that can be selected from the ui to move the zoo's entrance gate
+#[derive(Resource, Clone)]
+pub struct EntranceGateTool {
+    pub icon: Handle<Image>,
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    preview_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for EntranceGateTool {
+    fn from_world(world: &mut World) -> Self {
+        let icon = world.resource::<AssetServer>().load("test.png");
+
+        // TODO: replace with a proper entrance gate model
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(shape::Box::new(4.0, 3.0, 0.5).into());
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let color = Color::rgb(0.45, 0.3, 0.2);
+
+        Self {
+            icon,
+            mesh,
+            material: materials.add(color.into()),
+            preview_material: materials.add(color.with_a(0.6).into()),
+        }
+    }
+}
+
+impl UiDisplay for EntranceGateTool {
+    fn name(&self) -> String {
+        "Move Entrance".into()
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        GATE_COST.comma_separated()
+    }
+}
+
+impl PreviewData for EntranceGateTool {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        commands.spawn((
+            PbrBundle {
+                mesh: self.mesh.clone(),
+                material: self.preview_material.clone(),
+                ..default()
+            },
+            NotShadowCaster,
+            EntranceGatePreview,
+            Preview { cost: GATE_COST },
+        ));
+    }
+}
+
+/// Marker component for the entrance gate tool's preview
+#[derive(Component)]
+struct EntranceGatePreview;
+
+/// Spawns in the entrance gate where guests start arriving
+fn spawn_entrance_gate(
+    mut commands: Commands,
+    tool: Res<EntranceGateTool>,
+    terrain: Res<Terrain>,
+    entrance: Res<ZooEntrance>,
+) {
+    let height = terrain.height_at(entrance.position).unwrap_or_default();
+
+    commands.spawn((
+        PbrBundle {
+            mesh: tool.mesh.clone(),
+            material: tool.material.clone(),
+            transform: Transform::from_xyz(entrance.position.x, height, entrance.position.y),
+            ..default()
+        },
+        EntranceGate,
+        SeatOnTerrain,
+        Name::new("Entrance Gate"),
+    ));
+}
+
+fn handle_preview_movement(
+    cursor: CursorRaycast,
+    mut preview: Query<(&mut Transform, &mut Visibility), With<EntranceGatePreview>>,
+) {
+    if let Ok((mut transform, mut visibility)) = preview.get_single_mut() {
+        match cursor.ground_point() {
+            Some(position) => {
+                transform.translation = position;
+                *visibility = Visibility::Visible;
+            }
+
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// Moves the entrance gate to the previewed position, if it is within the zoo and can be afforded
+fn on_preview_place(
+    zoo: Res<Zoo>,
+    land: Res<Land>,
+    mut entrance: ResMut<ZooEntrance>,
+    preview: Query<&Transform, With<EntranceGatePreview>>,
+    mut gate: Query<&mut Transform, (With<EntranceGate>, Without<EntranceGatePreview>)>,
+
+    mut placements: EventReader<PlacePreview>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    for _ in placements.iter() {
+        let Ok(preview_transform) = preview.get_single() else { continue };
+        let Ok(mut gate_transform) = gate.get_single_mut() else { continue };
+
+        let position = preview_transform.translation.xz();
+        if !land.is_owned(position) || zoo.balance() < GATE_COST {
+            continue;
+        }
+
+        gate_transform.translation = preview_transform.translation;
+        entrance.position = position;
+
+        balance_changes.send(ZooBalanceChange {
+            amount: -GATE_COST,
+            category: LedgerCategory::Construction,
+        });
+    }
+}
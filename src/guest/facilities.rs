@@ -0,0 +1,222 @@
+use super::{
+    behavior::{Guest, GuestActivity},
+    litter::CarryingTrash,
+    needs::{GuestNeed, GuestNeeds},
+    thoughts::{GuestThought, GuestThoughts},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    objects::{Facility, FacilityData, FacilityKind},
+    placement::Preview,
+    zoo::ZooBalanceChange,
+    Currency,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub struct GuestFacilitiesPlugin;
+impl Plugin for GuestFacilitiesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, serve_guests);
+    }
+}
+
+// constants for guests using facilities
+/// Longest queue that a guest is willing to join
+const MAX_QUEUE: usize = 6;
+/// Distance from the center of a facility that guests wait to be served at
+const SERVICE_DISTANCE: f32 = 1.5;
+/// Fun gained from buying a souvenir at a gift shop
+const SOUVENIR_FUN: f32 = 0.25;
+
+/// Need of a guest that is satisfied by a kind of facility
+pub(super) fn satisfies(kind: FacilityKind) -> GuestNeed {
+    match kind {
+        FacilityKind::FoodStand => GuestNeed::Hunger,
+        FacilityKind::DrinkStand => GuestNeed::Thirst,
+        FacilityKind::Toilet => GuestNeed::Bladder,
+        FacilityKind::Bench => GuestNeed::Energy,
+        FacilityKind::GiftShop => GuestNeed::Fun,
+    }
+}
+
+/// What a guest knows about a facility when deciding where to go
+pub(super) struct FacilitySummary {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub need: GuestNeed,
+    pub price: Currency,
+    pub occupancy: usize,
+    pub open: bool,
+}
+
+impl FacilitySummary {
+    pub fn new(entity: Entity, position: Vec2, facility: &Facility, data: &FacilityData) -> Self {
+        Self {
+            entity,
+            position,
+            need: satisfies(data.kind),
+            price: data.price,
+            occupancy: facility.occupancy(),
+            open: facility.is_open(),
+        }
+    }
+}
+
+/// Picks the nearest facility that can satisfy a need, or the thought explaining why none could be used
+pub(super) fn seek_facility(
+    position: Vec2,
+    need: GuestNeed,
+    money: Currency,
+    facilities: &[FacilitySummary],
+) -> Result<GuestActivity, GuestThought> {
+    let open = facilities
+        .iter()
+        .filter(|facility| facility.need == need && facility.open)
+        .collect::<Vec<_>>();
+
+    if open.is_empty() {
+        return Err(GuestThought::CouldntFind(need));
+    }
+
+    let affordable = open
+        .into_iter()
+        .filter(|facility| facility.price <= money)
+        .collect::<Vec<_>>();
+
+    if affordable.is_empty() {
+        return Err(GuestThought::TooExpensive);
+    }
+
+    let nearest = affordable
+        .into_iter()
+        .filter(|facility| facility.occupancy < MAX_QUEUE)
+        .min_by(|a, b| {
+            a.position
+                .distance_squared(position)
+                .total_cmp(&b.position.distance_squared(position))
+        })
+        .ok_or(GuestThought::QueueTooLong)?;
+
+    // wait on the side of the facility facing the guest
+    let direction = (position - nearest.position)
+        .try_normalize()
+        .unwrap_or(Vec2::X);
+
+    Ok(GuestActivity::Seek {
+        destination: nearest.position + direction * SERVICE_DISTANCE,
+        facility: nearest.entity,
+    })
+}
+
+/// Applies the effects of being served by a facility to the guest's needs
+fn satisfy(kind: FacilityKind, needs: &mut GuestNeeds) {
+    match kind {
+        FacilityKind::FoodStand => needs.hunger = 1.0,
+        FacilityKind::DrinkStand => needs.thirst = 1.0,
+        FacilityKind::Toilet => needs.bladder = 1.0,
+        FacilityKind::Bench => needs.energy = 1.0,
+        FacilityKind::GiftShop => needs.fun = (needs.fun + SOUVENIR_FUN).min(1.0),
+    }
+}
+
+/// Moves queued guests through each facility, charging them as they are served and satisfying their needs once done
+fn serve_guests(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    facility_data: Res<Assets<FacilityData>>,
+    mut facilities: Query<(Entity, &mut Facility), Without<Preview>>,
+    mut guests: Query<(Entity, &mut Guest, &mut GuestNeeds, &mut GuestThoughts)>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    let delta = clock.delta_seconds();
+
+    // guests that have arrived at each facility, sorted so that guests arriving together always queue in the same order
+    let mut waiting = HashMap::<Entity, Vec<Entity>>::new();
+    for (entity, guest, ..) in guests.iter() {
+        if let GuestActivity::Queue { facility } = guest.activity {
+            waiting.entry(facility).or_default().push(entity);
+        }
+    }
+    waiting.values_mut().for_each(|guests| guests.sort());
+
+    for (entity, mut facility) in facilities.iter_mut() {
+        let Some(data) = facility_data.get(&facility.data) else { continue };
+        let facility = &mut *facility;
+        let arrived = waiting.remove(&entity).unwrap_or_default();
+
+        // guests that have given up or left the zoo no longer hold their place
+        facility.queue.retain(|guest| arrived.contains(guest));
+        facility
+            .serving
+            .retain(|(guest, _)| arrived.contains(guest));
+
+        for guest in arrived {
+            if !facility.queue.contains(&guest)
+                && !facility.serving.iter().any(|&(served, _)| served == guest)
+            {
+                facility.queue.push_back(guest);
+            }
+        }
+
+        // finish serving guests whose time is up
+        for (_, remaining) in facility.serving.iter_mut() {
+            *remaining -= delta;
+        }
+
+        for &(served, _) in facility
+            .serving
+            .iter()
+            .filter(|(_, remaining)| *remaining <= 0.0)
+        {
+            let Ok((_, mut guest, mut needs, mut thoughts)) = guests.get_mut(served) else {
+                continue;
+            };
+            satisfy(data.kind, &mut needs);
+            thoughts.forget(GuestThought::CouldntFind(satisfies(data.kind)));
+            guest.activity = GuestActivity::Idle { remaining: 0.0 };
+
+            // food and drink leave guests with trash to throw away
+            if matches!(
+                data.kind,
+                FacilityKind::FoodStand | FacilityKind::DrinkStand
+            ) {
+                commands.entity(served).insert(CarryingTrash);
+            }
+        }
+        facility.serving.retain(|(_, remaining)| *remaining > 0.0);
+
+        // start serving the next guests in line as space frees up
+        while facility.serving.len() < data.capacity {
+            let Some(next) = facility.queue.pop_front() else { break };
+            let Ok((_, mut guest, mut needs, mut thoughts)) = guests.get_mut(next) else {
+                continue;
+            };
+
+            // guests turned away at the counter go and find something else to do
+            if !facility.is_open() || needs.money < data.price {
+                thoughts.think(match facility.is_open() {
+                    true => GuestThought::TooExpensive,
+                    false => GuestThought::CouldntFind(satisfies(data.kind)),
+                });
+                guest.activity = GuestActivity::Idle { remaining: 0.0 };
+                continue;
+            }
+
+            needs.money -= data.price;
+            facility.revenue += data.price;
+            if let Some(stock) = facility.stock.as_mut() {
+                *stock -= 1;
+            }
+            if data.price > 0.0 {
+                balance_changes.send(ZooBalanceChange {
+                    amount: data.price,
+                    category: LedgerCategory::Sales,
+                });
+            }
+
+            facility.serving.push((next, data.service_time));
+        }
+    }
+}
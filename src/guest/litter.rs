@@ -0,0 +1,128 @@
+use super::{
+    behavior::Guest,
+    needs::GuestNeeds,
+    thoughts::{GuestThought, GuestThoughts},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    litter::{Litter, LitterBundle, LitterKind},
+    objects::{BinContents, Prop, PropData, PropFeature},
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct GuestLitterPlugin;
+impl Plugin for GuestLitterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, (dispose_trash, notice_litter));
+    }
+}
+
+// constants for guests and litter
+/// Seconds between guests looking for somewhere to throw away their trash
+const DISPOSAL_INTERVAL: f32 = 1.0;
+/// Furthest distance that guests will walk over to a bin
+const BIN_RANGE: f32 = 8.0;
+/// Chance each interval that a guest without a bin nearby drops their trash on the ground
+const LITTER_CHANCE: f32 = 0.15;
+/// Seconds between guests looking around at how clean the zoo is
+const NOTICE_INTERVAL: f32 = 1.0;
+/// Distance that guests notice litter from
+const NOTICE_RANGE: f32 = 6.0;
+/// Pieces of litter within range that make the surroundings look completely filthy
+const FILTHY_LITTER: f32 = 6.0;
+/// Fraction of the remaining difference in cleanliness that a guest's impression closes each interval
+const IMPRESSION_RATE: f32 = 0.2;
+/// Guests complain about the zoo being dirty once their impression of it drops below this
+const FILTHY_THRESHOLD: f32 = 0.5;
+
+/// Marks a guest holding onto trash from something they bought, until they find a bin or drop it
+#[derive(Component)]
+pub struct CarryingTrash;
+
+/// Lets guests carrying trash throw it in a nearby bin with room left, or otherwise drop it on the ground
+fn dispose_trash(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    prop_data: Res<Assets<PropData>>,
+    mut bins: Query<(&Prop, &mut BinContents, &Transform)>,
+    mut guests: Query<(Entity, &mut Guest, &Transform), With<CarryingTrash>>,
+    mut since_check: Local<f32>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < DISPOSAL_INTERVAL {
+        return;
+    }
+    *since_check = 0.0;
+
+    for (entity, mut guest, transform) in guests.iter_mut() {
+        let position = transform.translation.xz();
+
+        let bin = bins
+            .iter_mut()
+            .filter(|(prop, contents, bin_transform)| {
+                let Some(data) = prop_data.get(&prop.data) else { return false };
+                let PropFeature::Bin { capacity } = data.feature else { return false };
+
+                contents.amount < capacity
+                    && bin_transform.translation.xz().distance(position) <= BIN_RANGE
+            })
+            .min_by(|(_, _, a), (_, _, b)| {
+                a.translation
+                    .xz()
+                    .distance_squared(position)
+                    .total_cmp(&b.translation.xz().distance_squared(position))
+            });
+
+        match bin {
+            Some((_, mut contents, _)) => contents.amount += 1,
+
+            None if guest.rng.chance(LITTER_CHANCE) => {
+                commands.spawn(LitterBundle::new(
+                    LitterKind::Trash,
+                    None,
+                    transform.translation,
+                ));
+            }
+
+            // keep holding onto the trash for now
+            None => continue,
+        }
+
+        commands.entity(entity).remove::<CarryingTrash>();
+    }
+}
+
+/// Updates each guest's impression of how clean the zoo is from the litter around them
+fn notice_litter(
+    clock: Res<ZooClock>,
+    litter: Query<&Transform, With<Litter>>,
+    mut guests: Query<(&Transform, &mut GuestNeeds, &mut GuestThoughts), With<Guest>>,
+    mut since_check: Local<f32>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < NOTICE_INTERVAL {
+        return;
+    }
+    *since_check = 0.0;
+
+    let litter = litter
+        .iter()
+        .map(|transform| transform.translation.xz())
+        .collect::<Vec<_>>();
+
+    for (transform, mut needs, mut thoughts) in guests.iter_mut() {
+        let position = transform.translation.xz();
+        let nearby = litter
+            .iter()
+            .filter(|point| point.distance(position) <= NOTICE_RANGE)
+            .count();
+
+        let cleanliness = (1.0 - nearby as f32 / FILTHY_LITTER).max(0.0);
+        needs.cleanliness += (cleanliness - needs.cleanliness) * IMPRESSION_RATE;
+
+        match needs.cleanliness < FILTHY_THRESHOLD {
+            true => thoughts.think(GuestThought::Filthy),
+            false => thoughts.forget(GuestThought::Filthy),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+mod admission;
+mod alarm;
+mod arrival;
+mod behavior;
+mod entrance;
+mod facilities;
+mod litter;
+mod needs;
+mod render;
+mod thoughts;
+mod viewing;
+
+pub use admission::{Admission, ChangeTicketPrice, StartMarketingCampaign};
+pub use behavior::Guest;
+pub use entrance::EntranceGateTool;
+pub use needs::GuestHappiness;
+
+/// Guest simulation along with its rendering and the entrance gate
+pub struct GuestPlugin;
+impl Plugin for GuestPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            GuestSimulationPlugin,
+            entrance::GuestEntrancePlugin,
+            render::GuestRenderPlugin,
+        ));
+    }
+}
+
+/// Guest simulation on its own, without rendering or the entrance gate tool
+pub struct GuestSimulationPlugin;
+impl Plugin for GuestSimulationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            admission::GuestAdmissionPlugin,
+            alarm::GuestAlarmPlugin,
+            arrival::GuestArrivalPlugin,
+            behavior::GuestBehaviorPlugin,
+            facilities::GuestFacilitiesPlugin,
+            litter::GuestLitterPlugin,
+            needs::GuestNeedsPlugin,
+            viewing::GuestViewingPlugin,
+        ));
+    }
+}
@@ -0,0 +1,232 @@
+use super::{
+    behavior::{Guest, GuestActivity},
+    thoughts::{GuestThought, GuestThoughts},
+    viewing::GuestViewing,
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    rng::SeededRng,
+    zoo::ZooReputationChange,
+    Currency,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub struct GuestNeedsPlugin;
+impl Plugin for GuestNeedsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GuestHappiness>().add_systems(
+            Simulation,
+            (update_needs, update_happiness, update_reputation).chain(),
+        );
+    }
+}
+
+// constants for guest needs
+/// Fraction of the hunger meter lost each second
+const HUNGER_DECAY: f32 = 1.0 / 400.0;
+/// Fraction of the thirst meter lost each second
+const THIRST_DECAY: f32 = 1.0 / 300.0;
+/// Fraction of the bladder meter lost each second
+const BLADDER_DECAY: f32 = 1.0 / 350.0;
+/// Fraction of the energy meter lost each second while on their feet
+const ENERGY_DECAY: f32 = 1.0 / 300.0;
+/// Fraction of the fun meter lost each second while there are no animals to see
+const FUN_DECAY: f32 = 1.0 / 400.0;
+/// Fraction of the energy meter restored each second while resting
+const REST_RATE: f32 = 1.0 / 20.0;
+/// Guests go looking for whatever satisfies a need once it drops below this
+pub(super) const SEEK_THRESHOLD: f32 = 0.4;
+/// Guests start complaining about a need once it drops below this
+const COMPLAINT_THRESHOLD: f32 = 0.25;
+/// Guests give up on the zoo and go home once their happiness drops below this
+const GIVE_UP_HAPPINESS: f32 = 0.2;
+
+// constants for guest happiness affecting the zoo's reputation
+/// Seconds between the zoo's reputation being adjusted by guest happiness
+const REPUTATION_INTERVAL: f32 = 10.0;
+/// Average happiness that neither raises nor lowers reputation
+const NEUTRAL_HAPPINESS: f32 = 0.5;
+/// Reputation gained each interval for every point of average happiness above neutral
+const REPUTATION_PER_HAPPINESS: f32 = 4.0;
+
+/// Needs that guests can go looking to satisfy
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GuestNeed {
+    Hunger,
+    Thirst,
+    Bladder,
+    Energy,
+    Fun,
+}
+
+/// Meters for each of a guest's needs, ranging from 0 when desperate to 1 when fully satisfied
+#[derive(Component, Clone, Debug)]
+pub struct GuestNeeds {
+    pub hunger: f32,
+    pub thirst: f32,
+    pub bladder: f32,
+    pub energy: f32,
+    /// How clean the guest's surroundings have looked
+    pub cleanliness: f32,
+    /// How entertained the guest is, they go home satisfied once it is full
+    pub fun: f32,
+    /// Money the guest has left to spend
+    pub money: Currency,
+}
+
+impl GuestNeeds {
+    /// Needs of a guest just arriving at the zoo
+    pub fn arriving(rng: &mut SeededRng) -> Self {
+        Self {
+            hunger: rng.range(0.6..1.0),
+            thirst: rng.range(0.6..1.0),
+            bladder: rng.range(0.7..1.0),
+            energy: rng.range(0.7..1.0),
+            cleanliness: 1.0,
+            fun: rng.range(0.3..0.5),
+            money: rng.range(50.0..200.0).round(),
+        }
+    }
+
+    /// Overall happiness of the guest from 0 to 1, weighing each of their needs
+    pub fn happiness(&self) -> f32 {
+        self.hunger * 0.15
+            + self.thirst * 0.15
+            + self.bladder * 0.1
+            + self.energy * 0.1
+            + self.cleanliness * 0.1
+            + self.fun * 0.4
+    }
+
+    /// Returns true once the guest is either satisfied, too tired or too unhappy to stay
+    pub fn wants_to_leave(&self) -> bool {
+        self.fun >= 1.0 || self.energy <= 0.0 || self.happiness() < GIVE_UP_HAPPINESS
+    }
+
+    /// Return the lowest need that the guest should go looking to satisfy, if any
+    pub fn most_urgent(&self) -> Option<GuestNeed> {
+        [
+            (GuestNeed::Bladder, self.bladder),
+            (GuestNeed::Thirst, self.thirst),
+            (GuestNeed::Hunger, self.hunger),
+            (GuestNeed::Energy, self.energy),
+        ]
+        .into_iter()
+        .filter(|&(_, value)| value < SEEK_THRESHOLD)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(need, _)| need)
+    }
+}
+
+/// Summary of how every guest in the zoo is feeling
+#[derive(Resource, Default)]
+pub struct GuestHappiness {
+    /// Average happiness of all guests from 0 to 1, which is 0 when there are no guests
+    pub average: f32,
+    pub guests: usize,
+    /// Number of guests having each complaint, from most to least common
+    pub complaints: Vec<(GuestThought, usize)>,
+}
+
+/// Decays needs over time, satisfies them based on what the guest is doing and updates their thoughts
+fn update_needs(
+    clock: Res<ZooClock>,
+    mut guests: Query<(&Guest, &GuestViewing, &mut GuestNeeds, &mut GuestThoughts)>,
+) {
+    let delta = clock.delta_seconds();
+
+    for (guest, viewing, mut needs, mut thoughts) in guests.iter_mut() {
+        needs.hunger -= HUNGER_DECAY * delta;
+        needs.thirst -= THIRST_DECAY * delta;
+        needs.bladder -= BLADDER_DECAY * delta;
+
+        match guest.activity {
+            GuestActivity::Rest { .. } => needs.energy += REST_RATE * delta,
+            _ => needs.energy -= ENERGY_DECAY * delta,
+        }
+
+        // seeing animals is what guests have come for
+        match viewing.rate > 0.0 {
+            true => needs.fun += viewing.rate * delta,
+            false => needs.fun -= FUN_DECAY * delta,
+        }
+
+        needs.hunger = needs.hunger.clamp(0.0, 1.0);
+        needs.thirst = needs.thirst.clamp(0.0, 1.0);
+        needs.bladder = needs.bladder.clamp(0.0, 1.0);
+        needs.energy = needs.energy.clamp(0.0, 1.0);
+        needs.fun = needs.fun.clamp(0.0, 1.0);
+
+        // complain about needs while they are low, and stop once they have been satisfied
+        let complaints = [
+            (needs.hunger, GuestThought::Hungry),
+            (needs.thirst, GuestThought::Thirsty),
+            (needs.bladder, GuestThought::NeedToilet),
+            (needs.energy, GuestThought::Tired),
+            (needs.fun, GuestThought::Bored),
+        ];
+
+        for (value, thought) in complaints {
+            match value < COMPLAINT_THRESHOLD {
+                true => thoughts.think(thought),
+                false => thoughts.forget(thought),
+            }
+        }
+    }
+}
+
+/// Summarizes the happiness and complaints of every guest
+fn update_happiness(
+    mut happiness: ResMut<GuestHappiness>,
+    guests: Query<(&GuestNeeds, &GuestThoughts)>,
+) {
+    let mut total = 0.0;
+    let mut count = 0;
+    let mut complaints = HashMap::<GuestThought, usize>::new();
+
+    for (needs, thoughts) in guests.iter() {
+        total += needs.happiness();
+        count += 1;
+
+        for &thought in thoughts.iter().filter(|thought| thought.is_complaint()) {
+            *complaints.entry(thought).or_default() += 1;
+        }
+    }
+
+    happiness.guests = count;
+    happiness.average = match count {
+        0 => 0.0,
+        _ => total / count as f32,
+    };
+
+    // sort by message as well so that ties are always shown in the same order
+    let mut complaints = complaints.into_iter().collect::<Vec<_>>();
+    complaints.sort_by(|(a_thought, a), (b_thought, b)| {
+        b.cmp(a)
+            .then_with(|| a_thought.message().cmp(b_thought.message()))
+    });
+    happiness.complaints = complaints;
+}
+
+/// Periodically raises or lowers the zoo's reputation based on how happy its guests are
+fn update_reputation(
+    clock: Res<ZooClock>,
+    happiness: Res<GuestHappiness>,
+    mut reputation_changes: EventWriter<ZooReputationChange>,
+    mut since_update: Local<f32>,
+) {
+    *since_update += clock.delta_seconds();
+    if *since_update < REPUTATION_INTERVAL {
+        return;
+    }
+    *since_update = 0.0;
+
+    if happiness.guests == 0 {
+        return;
+    }
+
+    reputation_changes.send(ZooReputationChange {
+        amount: (happiness.average - NEUTRAL_HAPPINESS) * REPUTATION_PER_HAPPINESS,
+    });
+}
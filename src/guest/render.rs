@@ -0,0 +1,56 @@
+use super::behavior::Guest;
+use bevy::prelude::*;
+
+pub struct GuestRenderPlugin;
+impl Plugin for GuestRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GuestAssets>()
+            .add_systems(Update, render_new_guests);
+    }
+}
+
+/// Shared mesh and material that every guest is rendered with
+#[derive(Resource)]
+struct GuestAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for GuestAssets {
+    fn from_world(world: &mut World) -> Self {
+        // TODO: replace with a proper guest model
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(
+            shape::Capsule {
+                radius: 0.25,
+                depth: 1.2,
+                ..default()
+            }
+            .into(),
+        );
+
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(Color::rgb(0.2, 0.4, 0.8).into());
+
+        Self { mesh, material }
+    }
+}
+
+/// Gives newly arrived guests a model, kept separate so that the simulation can run without rendering
+fn render_new_guests(
+    mut commands: Commands,
+    guest_assets: Res<GuestAssets>,
+    new_guests: Query<Entity, Added<Guest>>,
+) {
+    for entity in new_guests.iter() {
+        commands.entity(entity).with_children(|parent| {
+            // raise the capsule so that it stands on the guest's position
+            parent.spawn(PbrBundle {
+                mesh: guest_assets.mesh.clone(),
+                material: guest_assets.material.clone(),
+                transform: Transform::from_xyz(0.0, 0.85, 0.0),
+                ..default()
+            });
+        });
+    }
+}
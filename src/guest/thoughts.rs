@@ -0,0 +1,94 @@
+use super::needs::GuestNeed;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// Most thoughts that a guest remembers at once
+const MAX_THOUGHTS: usize = 5;
+
+/// Short remark a guest has about their visit, explaining why they are happy or unhappy
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GuestThought {
+    Hungry,
+    Thirsty,
+    NeedToilet,
+    Tired,
+    Bored,
+    /// Went looking for something to satisfy a need but nothing was available
+    CouldntFind(GuestNeed),
+    /// Found somewhere to satisfy a need but couldn't afford it
+    TooExpensive,
+    /// Found somewhere to satisfy a need but the queue was too long to wait in
+    QueueTooLong,
+    /// Saw too much litter lying around
+    Filthy,
+    EnclosureEmpty,
+    CantSeeAnimals,
+    EnclosureTooSmall,
+    AnimalsUnhappy,
+    AnimalsThriving,
+    /// Ran into an animal that had escaped from its enclosure
+    AnimalLoose,
+}
+
+impl GuestThought {
+    /// Message shown to the player for this thought
+    pub fn message(&self) -> &'static str {
+        match self {
+            GuestThought::Hungry => "I'm hungry",
+            GuestThought::Thirsty => "I'm thirsty",
+            GuestThought::NeedToilet => "I really need the toilet",
+            GuestThought::Tired => "I'm exhausted",
+            GuestThought::Bored => "There's nothing to do here",
+            GuestThought::CouldntFind(GuestNeed::Hunger) => "Couldn't find anything to eat",
+            GuestThought::CouldntFind(GuestNeed::Thirst) => "Couldn't find anything to drink",
+            GuestThought::CouldntFind(GuestNeed::Bladder) => "Couldn't find a toilet",
+            GuestThought::CouldntFind(GuestNeed::Energy) => "Couldn't find anywhere to sit",
+            GuestThought::CouldntFind(GuestNeed::Fun) => "Couldn't find anything to see",
+            GuestThought::TooExpensive => "Everything here is far too expensive",
+            GuestThought::QueueTooLong => "The queue was far too long",
+            GuestThought::Filthy => "This place is filthy",
+            GuestThought::EnclosureEmpty => "There weren't any animals to see",
+            GuestThought::CantSeeAnimals => "I couldn't see any of the animals",
+            GuestThought::EnclosureTooSmall => "This enclosure is too small",
+            GuestThought::AnimalsUnhappy => "The animals look miserable",
+            GuestThought::AnimalsThriving => "The animals look well cared for",
+            GuestThought::AnimalLoose => "There's a wild animal on the loose!",
+        }
+    }
+
+    /// Returns true if the thought is a complaint about the zoo
+    pub fn is_complaint(&self) -> bool {
+        !matches!(self, GuestThought::AnimalsThriving)
+    }
+}
+
+/// Most recent thoughts of a guest, from oldest to newest
+#[derive(Component, Default)]
+pub struct GuestThoughts {
+    thoughts: VecDeque<GuestThought>,
+}
+
+impl GuestThoughts {
+    /// Iterates over the guest's thoughts, from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &GuestThought> {
+        self.thoughts.iter()
+    }
+
+    /// Remembers a new thought, unless the guest is already thinking it
+    pub fn think(&mut self, thought: GuestThought) {
+        if self.thoughts.contains(&thought) {
+            return;
+        }
+
+        if self.thoughts.len() >= MAX_THOUGHTS {
+            self.thoughts.pop_front();
+        }
+
+        self.thoughts.push_back(thought);
+    }
+
+    /// Forgets a thought once it no longer applies
+    pub fn forget(&mut self, thought: GuestThought) {
+        self.thoughts.retain(|&existing| existing != thought);
+    }
+}
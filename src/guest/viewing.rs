@@ -0,0 +1,232 @@
+use super::{
+    behavior::{Guest, GuestActivity},
+    thoughts::{GuestThought, GuestThoughts},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::{
+        utility::CollisionLayer, Animal, AnimalBehavior, AnimalData, AnimalNeeds, BarrierData,
+        BarrierFence, BarrierPost, Enclosure,
+    },
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+use bevy_rapier3d::prelude::*;
+
+pub struct GuestViewingPlugin;
+impl Plugin for GuestViewingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, view_exhibits);
+    }
+}
+
+// constants for viewing exhibits
+/// Seconds between guests looking around for animals to see
+const VIEWING_INTERVAL: f32 = 0.5;
+/// Furthest distance outside of an enclosure's boundary that guests can view it from
+const VIEWING_RANGE: f32 = 10.0;
+/// Height of a guest's eyes above the ground
+const EYE_HEIGHT: f32 = 1.6;
+/// Barriers lower than this can be seen over entirely
+const SEE_OVER_HEIGHT: f32 = 1.3;
+/// Most obstacles that a line of sight passes through before the view is considered blocked
+const MAX_OBSTACLES: usize = 4;
+/// Most animals within a single enclosure that a guest looks at
+const MAX_VIEWED_ANIMALS: usize = 8;
+/// Excitement each second from a fully visible and healthy animal of the most popular species
+const EXCITEMENT_PER_ANIMAL: f32 = 1.0 / 40.0;
+/// Most excitement gained each second, no matter how many animals are visible
+const MAX_EXCITEMENT_RATE: f32 = 1.0 / 15.0;
+
+// constants for judging exhibits
+/// Visible residents below this average space need make an enclosure look too small
+const CRAMPED_SPACE: f32 = 0.5;
+/// Visible residents below this average welfare look unhappy
+const UNHAPPY_WELFARE: f32 = 0.4;
+/// Visible residents above this average welfare look well cared for
+const THRIVING_WELFARE: f32 = 0.8;
+
+/// What a guest can currently see of the nearest exhibit
+#[derive(Component, Default)]
+pub struct GuestViewing {
+    /// Enclosure within viewing range of the guest, if any
+    pub enclosure: Option<Entity>,
+    /// Number of animals that could be seen the last time the guest looked
+    pub visible: usize,
+    /// Excitement gained each second from what is currently visible, which entertains the guest
+    pub rate: f32,
+    /// Total excitement gained over the guest's visit
+    pub excitement: f32,
+}
+
+/// Utility system parameter for checking what can be seen through the zoo's barriers
+#[derive(SystemParam)]
+struct LineOfSight<'w, 's> {
+    rapier: Res<'w, RapierContext>,
+    barrier_data: Res<'w, Assets<BarrierData>>,
+    posts: Query<'w, 's, &'static BarrierPost>,
+    fences: Query<'w, 's, &'static BarrierFence>,
+}
+
+impl<'w, 's> LineOfSight<'w, 's> {
+    /// Fraction of the target that can be seen from the eye, from 0 when blocked to 1 when in clear view
+    ///
+    /// Rays pass through low and see-through barriers, but are blocked by any other object in the way
+    fn visibility(&self, eye: Vec3, target_entity: Entity, target: Vec3) -> f32 {
+        let mut origin = eye;
+        let mut visibility = 1.0;
+        let mut passed = None;
+
+        for _ in 0..MAX_OBSTACLES {
+            let to_target = target - origin;
+            let distance = to_target.length();
+            let direction = to_target / distance;
+
+            let mut filter = CollisionLayer::Object.filter();
+            if let Some(entity) = passed {
+                filter = filter.exclude_collider(entity);
+            }
+
+            // nothing in the way of the target
+            let Some((hit, toi)) = self
+                .rapier
+                .cast_ray(origin, direction, distance, true, filter)
+            else {
+                return visibility;
+            };
+            if hit == target_entity {
+                return visibility;
+            }
+
+            // only barriers can be seen through, anything else blocks the view
+            let barrier = self
+                .posts
+                .get(hit)
+                .map(|post| &post.data)
+                .or_else(|_| self.fences.get(hit).map(|fence| &fence.data));
+            let Some(barrier) = barrier
+                .ok()
+                .and_then(|handle| self.barrier_data.get(handle))
+            else {
+                return 0.0;
+            };
+
+            if barrier.height >= SEE_OVER_HEIGHT {
+                visibility *= barrier.transparency;
+            }
+
+            if visibility <= 0.0 {
+                return 0.0;
+            }
+
+            // continue the ray from the other side of the barrier
+            origin += direction * toi;
+            passed = Some(hit);
+        }
+
+        0.0
+    }
+}
+
+/// Periodically has each guest look for animals in the nearest enclosure, judging what they can see
+fn view_exhibits(
+    clock: Res<ZooClock>,
+    line_of_sight: LineOfSight,
+    animal_data: Res<Assets<AnimalData>>,
+    enclosures: Query<(Entity, &Enclosure)>,
+    animals: Query<(Entity, &Animal, &AnimalBehavior, &AnimalNeeds, &Transform)>,
+    mut guests: Query<(&Guest, &Transform, &mut GuestViewing, &mut GuestThoughts)>,
+    mut since_look: Local<f32>,
+) {
+    let delta = clock.delta_seconds();
+    for (_, _, mut viewing, _) in guests.iter_mut() {
+        viewing.excitement += viewing.rate * delta;
+    }
+
+    *since_look += delta;
+    if *since_look < VIEWING_INTERVAL {
+        return;
+    }
+    *since_look = 0.0;
+
+    for (guest, transform, mut viewing, mut thoughts) in guests.iter_mut() {
+        let position = transform.translation.xz();
+
+        // guests on their way out have stopped paying attention
+        let nearest = enclosures
+            .iter()
+            .filter(|_| guest.activity != GuestActivity::Leave)
+            .filter(|(_, enclosure)| !enclosure.contains(position))
+            .map(|(entity, enclosure)| (entity, enclosure.polygon.distance_to_boundary(position)))
+            .filter(|&(_, distance)| distance <= VIEWING_RANGE)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(entity, _)| entity);
+
+        viewing.enclosure = nearest;
+        viewing.visible = 0;
+        viewing.rate = 0.0;
+
+        let Some(enclosure) = nearest else { continue };
+
+        // only guests standing at the enclosure take the time to judge it
+        let judging = matches!(
+            guest.activity,
+            GuestActivity::View { enclosure: viewed, .. } if viewed == enclosure
+        );
+
+        let eye = transform.translation + Vec3::Y * EYE_HEIGHT;
+        let mut residents = 0;
+        let mut excitement = 0.0;
+        let mut space = 0.0;
+        let mut welfare = 0.0;
+
+        for (entity, animal, _, needs, animal_transform) in animals
+            .iter()
+            .filter(|(_, _, behavior, _, _)| behavior.enclosure == enclosure)
+            .take(MAX_VIEWED_ANIMALS)
+        {
+            let Some(data) = animal_data.get(&animal.data) else {
+                continue;
+            };
+            residents += 1;
+
+            let target = animal_transform.translation + Vec3::Y * data.size * 0.25;
+            let visibility = line_of_sight.visibility(eye, entity, target);
+            if visibility <= 0.0 {
+                continue;
+            }
+
+            // healthy animals of popular species are the most exciting to see
+            excitement += data.popularity * (0.5 + 0.5 * needs.welfare()) * visibility;
+            space += needs.space;
+            welfare += needs.welfare();
+            viewing.visible += 1;
+        }
+
+        let mut rate = (excitement * EXCITEMENT_PER_ANIMAL).min(MAX_EXCITEMENT_RATE);
+
+        if judging {
+            if residents == 0 {
+                thoughts.think(GuestThought::EnclosureEmpty);
+            } else if viewing.visible == 0 {
+                thoughts.think(GuestThought::CantSeeAnimals);
+            } else {
+                let visible = viewing.visible as f32;
+
+                // cramped or miserable animals aren't much fun to look at
+                if space / visible < CRAMPED_SPACE {
+                    thoughts.think(GuestThought::EnclosureTooSmall);
+                    rate *= 0.5;
+                }
+
+                if welfare / visible < UNHAPPY_WELFARE {
+                    thoughts.think(GuestThought::AnimalsUnhappy);
+                    rate *= 0.5;
+                } else if welfare / visible > THRIVING_WELFARE {
+                    thoughts.think(GuestThought::AnimalsThriving);
+                }
+            }
+        }
+
+        viewing.rate = rate;
+    }
+}
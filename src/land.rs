@@ -0,0 +1,400 @@
+use crate::{
+    camera::CursorRaycast,
+    finance::LedgerCategory,
+    placement::{PlacePreview, Preview, PreviewData},
+    terrain::Terrain,
+    ui::UiDisplay,
+    zoo::{Zoo, ZooBalanceChange},
+    Currency, CurrencyFormat,
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct LandPlugin;
+impl Plugin for LandPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Land>()
+            .init_resource::<LandPurchaseTool>()
+            .add_systems(Update, (draw_land_boundaries, on_purchase_place));
+    }
+}
+
+/// Side length of a single land parcel in meters
+const PARCEL_SIZE: f32 = 16.0;
+/// Number of parcels along each side of the map
+const PARCELS: usize = (Terrain::SIZE / PARCEL_SIZE) as usize;
+
+// constants for land pricing
+const BASE_PARCEL_PRICE: Currency = 1000.0;
+/// Additional price per parcel of distance from the center of the map
+const PARCEL_PRICE_PER_DISTANCE: Currency = 500.0;
+
+/// Ownership state of a single parcel of land
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParcelStatus {
+    /// Part of the zoo, objects can be placed here
+    Owned,
+    /// Can be purchased once the zoo owns a neighboring parcel
+    ForSale { price: Currency },
+    /// Can never be purchased
+    NotForSale,
+}
+
+/// Division of the map into parcels of land that the zoo can own
+#[derive(Resource)]
+pub struct Land {
+    parcels: Vec<ParcelStatus>,
+}
+
+impl Default for Land {
+    fn default() -> Self {
+        let center = Vec2::splat(PARCELS as f32 / 2.0);
+        let max = PARCELS as u32 - 1;
+
+        let parcels = (0..PARCELS * PARCELS)
+            .map(|index| {
+                let parcel = UVec2::new((index % PARCELS) as u32, (index / PARCELS) as u32);
+                let distance = (parcel.as_vec2() + 0.5).distance(center);
+
+                if parcel.x == 0 || parcel.y == 0 || parcel.x == max || parcel.y == max {
+                    // the outermost ring of the map is kept as scenery
+                    ParcelStatus::NotForSale
+                } else if distance < 1.0 {
+                    // zoo starts with the center 4 parcels
+                    ParcelStatus::Owned
+                } else {
+                    ParcelStatus::ForSale {
+                        price: BASE_PARCEL_PRICE + PARCEL_PRICE_PER_DISTANCE * distance.floor(),
+                    }
+                }
+            })
+            .collect();
+
+        Self { parcels }
+    }
+}
+
+impl Land {
+    /// Return the parcel containing the given point on the XZ-plane, if it is within the map
+    pub fn parcel_at(&self, point: Vec2) -> Option<UVec2> {
+        let parcel = ((point + Terrain::SIZE / 2.0) / PARCEL_SIZE).floor();
+
+        match parcel.cmpge(Vec2::ZERO).all() && parcel.cmplt(Vec2::splat(PARCELS as f32)).all() {
+            true => Some(parcel.as_uvec2()),
+            false => None,
+        }
+    }
+
+    /// Return the ownership state of the given parcel
+    pub fn status(&self, parcel: UVec2) -> ParcelStatus {
+        self.parcels[Self::index(parcel)]
+    }
+
+    /// Returns true if the given point on the XZ-plane is on land owned by the zoo
+    pub fn is_owned(&self, point: Vec2) -> bool {
+        self.parcel_at(point)
+            .is_some_and(|parcel| self.status(parcel) == ParcelStatus::Owned)
+    }
+
+    /// Returns true if the entire line between both points on the XZ-plane is on land owned by the zoo
+    pub fn is_owned_between(&self, from: Vec2, to: Vec2) -> bool {
+        const SAMPLE_SPACING: f32 = 1.0;
+
+        let samples = (from.distance(to) / SAMPLE_SPACING).ceil().max(1.0) as usize;
+        (0..=samples).all(|i| self.is_owned(from.lerp(to, i as f32 / samples as f32)))
+    }
+
+    /// Return the price of the given parcel if it is for sale and borders land owned by the zoo
+    pub fn purchase_price(&self, parcel: UVec2) -> Option<Currency> {
+        let ParcelStatus::ForSale { price } = self.status(parcel) else { return None };
+
+        let borders_owned = self
+            .neighbors(parcel)
+            .any(|neighbor| self.status(neighbor) == ParcelStatus::Owned);
+
+        borders_owned.then_some(price)
+    }
+
+    /// Area covered by the given parcel on the XZ-plane
+    pub fn parcel_bounds(&self, parcel: UVec2) -> Rect {
+        let min = parcel.as_vec2() * PARCEL_SIZE - Terrain::SIZE / 2.0;
+        Rect::from_corners(min, min + PARCEL_SIZE)
+    }
+
+    /// Iterates over the parcels sharing an edge with the given parcel
+    fn neighbors(&self, parcel: UVec2) -> impl Iterator<Item = UVec2> {
+        [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y]
+            .into_iter()
+            .map(move |offset| parcel.as_ivec2() + offset)
+            .filter(|neighbor| {
+                neighbor.cmpge(IVec2::ZERO).all()
+                    && neighbor.cmplt(IVec2::splat(PARCELS as i32)).all()
+            })
+            .map(|neighbor| neighbor.as_uvec2())
+    }
+
+    fn index(parcel: UVec2) -> usize {
+        parcel.y as usize * PARCELS + parcel.x as usize
+    }
+}
+
+/// Tool that can be selected from the ui to purchase parcels of land
+#[derive(Resource, Clone)]
+pub struct LandPurchaseTool {
+    pub icon: Handle<Image>,
+}
+
+impl FromWorld for LandPurchaseTool {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        Self {
+            icon: asset_server.load("test.png"),
+        }
+    }
+}
+
+impl UiDisplay for LandPurchaseTool {
+    fn name(&self) -> String {
+        "Buy Land".into()
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        format!("From {}", BASE_PARCEL_PRICE.comma_separated())
+    }
+}
+
+impl PreviewData for LandPurchaseTool {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpatialBundle::default(),
+            LandPurchasePreview,
+            Preview { cost: 0.0 },
+        ));
+    }
+}
+
+/// Marker component for the land purchasing tool's preview
+#[derive(Component)]
+struct LandPurchasePreview;
+
+/// Draws the zoo's boundary, along with purchasable parcels while the land purchasing tool is selected
+fn draw_land_boundaries(
+    land: Res<Land>,
+    terrain: Res<Terrain>,
+    cursor: CursorRaycast,
+    purchase_preview: Query<(), With<LandPurchasePreview>>,
+    mut gizmos: Gizmos,
+) {
+    const BOUNDARY_COLOR: Color = Color::ORANGE;
+    const FOR_SALE_COLOR: Color = Color::YELLOW;
+    const HOVERED_COLOR: Color = Color::WHITE;
+
+    // draws a line between two corners, following the terrain's surface
+    let mut draw_edge = |from: Vec2, to: Vec2, color: Color| {
+        let samples = (from.distance(to) / 1.0) as usize;
+        let points = (0..=samples).filter_map(|i| {
+            let point = from.lerp(to, i as f32 / samples as f32);
+            terrain
+                .height_at(point)
+                .map(|height| Vec3::new(point.x, height + 0.05, point.y))
+        });
+
+        gizmos.linestrip(points, color);
+    };
+
+    for x in 0..PARCELS as u32 {
+        for z in 0..PARCELS as u32 {
+            let parcel = UVec2::new(x, z);
+            if land.status(parcel) != ParcelStatus::Owned {
+                continue;
+            }
+
+            // only draw edges of owned parcels that don't border other owned parcels
+            let bounds = land.parcel_bounds(parcel);
+            let edges = [
+                (
+                    IVec2::NEG_Y,
+                    Vec2::new(bounds.min.x, bounds.min.y),
+                    Vec2::new(bounds.max.x, bounds.min.y),
+                ),
+                (
+                    IVec2::X,
+                    Vec2::new(bounds.max.x, bounds.min.y),
+                    Vec2::new(bounds.max.x, bounds.max.y),
+                ),
+                (
+                    IVec2::Y,
+                    Vec2::new(bounds.max.x, bounds.max.y),
+                    Vec2::new(bounds.min.x, bounds.max.y),
+                ),
+                (
+                    IVec2::NEG_X,
+                    Vec2::new(bounds.min.x, bounds.max.y),
+                    Vec2::new(bounds.min.x, bounds.min.y),
+                ),
+            ];
+
+            for (offset, from, to) in edges {
+                let borders_owned = land.neighbors(parcel).any(|neighbor| {
+                    neighbor.as_ivec2() == parcel.as_ivec2() + offset
+                        && land.status(neighbor) == ParcelStatus::Owned
+                });
+
+                if !borders_owned {
+                    draw_edge(from, to, BOUNDARY_COLOR);
+                }
+            }
+        }
+    }
+
+    // purchasable parcels are only shown while the purchasing tool is selected
+    if purchase_preview.is_empty() {
+        return;
+    }
+
+    let hovered = cursor
+        .ground_point()
+        .and_then(|point| land.parcel_at(point.xz()));
+
+    for x in 0..PARCELS as u32 {
+        for z in 0..PARCELS as u32 {
+            let parcel = UVec2::new(x, z);
+            if land.purchase_price(parcel).is_none() {
+                continue;
+            }
+
+            let color = match hovered == Some(parcel) {
+                true => HOVERED_COLOR,
+                false => FOR_SALE_COLOR,
+            };
+
+            // inset slightly so the outline doesn't overlap the zoo's boundary
+            let bounds = land.parcel_bounds(parcel).inset(-0.25);
+            let corners = [
+                Vec2::new(bounds.min.x, bounds.min.y),
+                Vec2::new(bounds.max.x, bounds.min.y),
+                Vec2::new(bounds.max.x, bounds.max.y),
+                Vec2::new(bounds.min.x, bounds.max.y),
+            ];
+
+            for i in 0..corners.len() {
+                draw_edge(corners[i], corners[(i + 1) % corners.len()], color);
+            }
+        }
+    }
+}
+
+/// Purchases the hovered parcel when placed, if it is purchasable and the zoo can afford it
+fn on_purchase_place(
+    mut land: ResMut<Land>,
+    zoo: Res<Zoo>,
+    cursor: CursorRaycast,
+    purchase_preview: Query<(), With<LandPurchasePreview>>,
+
+    mut placements: EventReader<PlacePreview>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    for _ in placements.iter() {
+        if purchase_preview.is_empty() {
+            continue;
+        }
+
+        let Some(point) = cursor.ground_point() else { continue };
+        let Some(parcel) = land.parcel_at(point.xz()) else { continue };
+        let Some(price) = land.purchase_price(parcel) else { continue };
+
+        if zoo.balance() < price {
+            continue;
+        }
+
+        let index = Land::index(parcel);
+        land.parcels[index] = ParcelStatus::Owned;
+
+        balance_changes.send(ZooBalanceChange {
+            amount: -price,
+            category: LedgerCategory::Land,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One of the parcels that the zoo starts with, with a corner at the center of the map
+    const STARTING_PARCEL: UVec2 = UVec2::new(PARCELS as u32 / 2, PARCELS as u32 / 2);
+
+    #[test]
+    fn zoo_starts_with_the_center_parcels() {
+        let land = Land::default();
+
+        assert!(land.is_owned(Vec2::ZERO));
+        assert!(land.is_owned(Vec2::splat(-PARCEL_SIZE + 0.5)));
+        assert!(land.is_owned(Vec2::splat(PARCEL_SIZE - 0.5)));
+        assert!(!land.is_owned(Vec2::splat(PARCEL_SIZE + 0.5)));
+    }
+
+    #[test]
+    fn parcels_are_found_from_points() {
+        let land = Land::default();
+
+        assert_eq!(land.parcel_at(Vec2::splat(0.5)), Some(STARTING_PARCEL));
+        assert_eq!(land.parcel_at(Vec2::splat(-0.5)), Some(STARTING_PARCEL - 1));
+        assert_eq!(
+            land.parcel_at(Vec2::splat(-Terrain::SIZE / 2.0)),
+            Some(UVec2::ZERO)
+        );
+    }
+
+    #[test]
+    fn points_outside_the_map_have_no_parcel() {
+        let land = Land::default();
+        let edge = Terrain::SIZE / 2.0;
+
+        assert_eq!(land.parcel_at(Vec2::new(edge, 0.0)), None);
+        assert_eq!(land.parcel_at(Vec2::new(0.0, -edge - 0.1)), None);
+    }
+
+    #[test]
+    fn parcel_bounds_contain_their_points() {
+        let land = Land::default();
+        let bounds = land.parcel_bounds(STARTING_PARCEL);
+
+        assert_eq!(bounds.min, Vec2::ZERO);
+        assert_eq!(bounds.max, Vec2::splat(PARCEL_SIZE));
+        assert_eq!(land.parcel_at(bounds.center()), Some(STARTING_PARCEL));
+    }
+
+    #[test]
+    fn only_parcels_bordering_the_zoo_can_be_purchased() {
+        let land = Land::default();
+        let bordering = STARTING_PARCEL + UVec2::X;
+        let distant = STARTING_PARCEL + UVec2::X * 2;
+
+        assert!(land.purchase_price(bordering).is_some());
+        assert_eq!(land.purchase_price(distant), None);
+        assert_eq!(land.purchase_price(STARTING_PARCEL), None);
+        assert_eq!(land.purchase_price(UVec2::ZERO), None);
+    }
+
+    #[test]
+    fn purchase_price_grows_with_distance_from_the_center() {
+        let mut land = Land::default();
+        let bordering = STARTING_PARCEL + UVec2::X;
+        let distant = STARTING_PARCEL + UVec2::X * 2;
+
+        let near_price = land.purchase_price(bordering).unwrap();
+        assert_eq!(near_price, BASE_PARCEL_PRICE + PARCEL_PRICE_PER_DISTANCE);
+
+        land.parcels[Land::index(bordering)] = ParcelStatus::Owned;
+        let far_price = land.purchase_price(distant).unwrap();
+        assert_eq!(
+            far_price,
+            BASE_PARCEL_PRICE + PARCEL_PRICE_PER_DISTANCE * 2.0
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::terrain::SeatOnTerrain;
+use bevy::prelude::*;
+
+pub struct LitterPlugin;
+impl Plugin for LitterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LitterAssets>()
+            .add_systems(Update, render_new_litter);
+    }
+}
+
+/// Types of mess that can build up around the zoo
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LitterKind {
+    /// Dropped by guests who couldn't find a bin, swept up by janitors
+    Trash,
+    /// Left by animals within their enclosure, cleaned up by zookeepers
+    Waste,
+}
+
+/// Mess lying on the ground until a member of staff cleans it up
+#[derive(Component)]
+pub struct Litter {
+    pub kind: LitterKind,
+    /// Enclosure that the litter was left within, if any
+    pub enclosure: Option<Entity>,
+}
+
+/// Common data for a piece of litter lying at a point in the world
+#[derive(Bundle)]
+pub struct LitterBundle {
+    pub litter: Litter,
+    pub spatial: SpatialBundle,
+    pub seat: SeatOnTerrain,
+}
+
+impl LitterBundle {
+    pub fn new(kind: LitterKind, enclosure: Option<Entity>, position: Vec3) -> Self {
+        Self {
+            litter: Litter { kind, enclosure },
+            spatial: SpatialBundle::from_transform(Transform::from_translation(position)),
+            seat: SeatOnTerrain,
+        }
+    }
+}
+
+/// Shared mesh and materials that litter is rendered with
+#[derive(Resource)]
+struct LitterAssets {
+    mesh: Handle<Mesh>,
+    trash: Handle<StandardMaterial>,
+    waste: Handle<StandardMaterial>,
+}
+
+impl FromWorld for LitterAssets {
+    fn from_world(world: &mut World) -> Self {
+        // TODO: replace with proper litter models
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(shape::Box::new(0.3, 0.05, 0.3).into());
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+
+        Self {
+            mesh,
+            trash: materials.add(Color::rgb(0.85, 0.85, 0.8).into()),
+            waste: materials.add(Color::rgb(0.35, 0.25, 0.1).into()),
+        }
+    }
+}
+
+/// Gives newly dropped litter a model, kept separate so that the simulation can run without rendering
+fn render_new_litter(
+    mut commands: Commands,
+    litter_assets: Res<LitterAssets>,
+    new_litter: Query<(Entity, &Litter), Added<Litter>>,
+) {
+    for (entity, litter) in new_litter.iter() {
+        let material = match litter.kind {
+            LitterKind::Trash => litter_assets.trash.clone(),
+            LitterKind::Waste => litter_assets.waste.clone(),
+        };
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn(PbrBundle {
+                mesh: litter_assets.mesh.clone(),
+                material,
+                ..default()
+            });
+        });
+    }
+}
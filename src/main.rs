@@ -1,10 +1,20 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
-use objects::utility::{ColliderMesh, CollisionLayer};
 
 mod camera;
+mod clock;
+mod finance;
+mod graph;
+mod guest;
+mod land;
+mod litter;
 mod objects;
 mod placement;
+mod polygon;
+mod rating;
+mod rng;
+mod staff;
+mod terrain;
 mod ui;
 mod zoo;
 
@@ -20,11 +30,20 @@ fn main() {
         ))
         .add_plugins((
             camera::ControllableCameraPlugin,
+            clock::ClockPlugin,
+            finance::FinancePlugin,
+            guest::GuestPlugin,
+            land::LandPlugin,
+            litter::LitterPlugin,
             objects::ObjectPlugin,
             placement::PlacementPlugin,
+            rating::RatingPlugin,
+            staff::StaffPlugin,
+            terrain::TerrainPlugin,
             ui::UiPlugin,
             zoo::ZooPlugin,
         ))
+        .init_resource::<rng::SeededRng>()
         .add_systems(Startup, setup_demo_scene)
         .run();
 }
@@ -33,13 +52,14 @@ fn main() {
 type Currency = f32;
 
 trait CurrencyFormat {
-    /// Format currency with commas separating every 3 digits, such as $12,345,678
+    /// Format currency with commas separating every 3 digits, such as $12,345,678 or -$1,234
     fn comma_separated(self) -> String;
 }
 
 impl CurrencyFormat for Currency {
     fn comma_separated(self) -> String {
-        let string_repr = format!("{:.0}", self);
+        let rounded = self.round();
+        let string_repr = format!("{:.0}", rounded.abs());
 
         // add commas between every 3 digits
         let with_commas = string_repr
@@ -56,16 +76,15 @@ impl CurrencyFormat for Currency {
             .iter()
             .collect::<String>();
 
-        // add dollar sign
-        format!("${}", with_commas)
+        // add dollar sign, after the minus sign of negative amounts
+        match rounded < 0.0 {
+            true => format!("-${}", with_commas),
+            false => format!("${}", with_commas),
+        }
     }
 }
 
-fn setup_demo_scene(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
+fn setup_demo_scene(mut commands: Commands) {
     // spawn in lights
     commands.spawn(DirectionalLightBundle {
         directional_light: DirectionalLight {
@@ -75,19 +94,4 @@ fn setup_demo_scene(
         transform: Transform::from_xyz(5.0, 10.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
         ..default()
     });
-
-    // ground plane
-    let ground_mesh = meshes.add(Mesh::from(shape::Plane::from_size(10.0)));
-    commands.spawn((
-        PbrBundle {
-            mesh: ground_mesh.clone(),
-            material: materials.add(Color::DARK_GREEN.into()),
-            ..default()
-        },
-        ColliderMesh {
-            mesh: ground_mesh,
-            rb: RigidBody::Fixed,
-            membership: CollisionLayer::Ground,
-        },
-    ));
 }
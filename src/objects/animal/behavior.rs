@@ -0,0 +1,221 @@
+use super::{components::Animal, AnimalData};
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::{Enclosure, Prop},
+    placement::Preview,
+    rng::SeededRng,
+    terrain::Terrain,
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct AnimalBehaviorPlugin;
+impl Plugin for AnimalBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, update_behavior);
+    }
+}
+
+// constants for animal behavior
+/// Walking speed in meters per second for each meter of an animal's size
+pub(super) const WALK_SPEED_PER_SIZE: f32 = 0.6;
+/// Distance from a destination that an animal is considered to have arrived at it
+pub(super) const ARRIVAL_DISTANCE: f32 = 0.25;
+/// Number of random points tried when looking for a destination within the enclosure
+const DESTINATION_ATTEMPTS: usize = 16;
+/// Range of seconds that an animal stands still for
+const IDLE_DURATION: std::ops::Range<f32> = 2.0..8.0;
+/// Range of seconds that an animal lies down for
+const REST_DURATION: std::ops::Range<f32> = 15.0..40.0;
+/// Range of seconds that an animal lingers at a point of interest
+const VISIT_DURATION: std::ops::Range<f32> = 4.0..12.0;
+
+/// What an animal is currently doing
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Activity {
+    /// Standing in place for the remaining number of seconds
+    Idle { remaining: f32 },
+    /// Lying down for the remaining number of seconds
+    Rest { remaining: f32 },
+    /// Walking towards a random destination within the enclosure
+    Roam { destination: Vec2 },
+    /// Walking towards a point of interest within the enclosure
+    Visit { destination: Vec2, target: Entity },
+    /// Lingering at a point of interest for the remaining number of seconds
+    Linger { remaining: f32, target: Entity },
+}
+
+/// Decision making state for an animal living within an enclosure
+#[derive(Component)]
+pub struct AnimalBehavior {
+    /// Enclosure that the animal lives within and is unable to leave
+    pub enclosure: Entity,
+    pub activity: Activity,
+    /// Each animal has its own generator so that its decisions don't depend on the order animals are updated in
+    pub(super) rng: SeededRng,
+}
+
+impl AnimalBehavior {
+    pub fn new(enclosure: Entity, rng: SeededRng) -> Self {
+        Self {
+            enclosure,
+            activity: Activity::Idle { remaining: 0.0 },
+            rng,
+        }
+    }
+
+    /// Picks the next activity once the current one has finished
+    fn choose_activity(
+        &mut self,
+        position: Vec2,
+        margin: f32,
+        enclosure: &Enclosure,
+        points_of_interest: &[(Entity, Vec2)],
+        is_passable: impl Fn(Vec2) -> bool,
+    ) -> Activity {
+        let roll = self.rng.next_f32();
+
+        if roll < 0.15 {
+            if let Some(&(target, point)) = self.rng.pick(points_of_interest) {
+                return Self::visit(position, margin, target, point);
+            }
+        }
+
+        if roll < 0.55 {
+            let destination = self.random_point(enclosure, margin, &is_passable);
+            if let Some(destination) = destination {
+                return Activity::Roam { destination };
+            }
+        }
+
+        match roll < 0.9 {
+            true => Activity::Idle {
+                remaining: self.rng.range(IDLE_DURATION),
+            },
+            false => self.rest(),
+        }
+    }
+
+    /// Activity for walking over to stand beside a point of interest, rather than on top of it
+    pub(super) fn visit(position: Vec2, margin: f32, target: Entity, point: Vec2) -> Activity {
+        Activity::Visit {
+            destination: point + (position - point).normalize_or_zero() * margin,
+            target,
+        }
+    }
+
+    /// Activity for lying down for a random amount of time
+    pub(super) fn rest(&mut self) -> Activity {
+        Activity::Rest {
+            remaining: self.rng.range(REST_DURATION),
+        }
+    }
+
+    /// Searches for a random point within the enclosure, at least `margin` away from its boundary
+    pub(super) fn random_point(
+        &mut self,
+        enclosure: &Enclosure,
+        margin: f32,
+        accept: impl Fn(Vec2) -> bool,
+    ) -> Option<Vec2> {
+        let bounds = enclosure.polygon.bounds();
+
+        (0..DESTINATION_ATTEMPTS)
+            .map(|_| {
+                Vec2::new(
+                    self.rng.range(bounds.min.x..bounds.max.x),
+                    self.rng.range(bounds.min.y..bounds.max.y),
+                )
+            })
+            .find(|&point| {
+                enclosure.contains(point)
+                    && enclosure.polygon.distance_to_boundary(point) >= margin
+                    && accept(point)
+            })
+    }
+}
+
+/// Placed props that animals can walk up to and linger at
+type EnclosureProps<'w, 's> =
+    Query<'w, 's, (Entity, &'static Transform), (With<Prop>, Without<Preview>, Without<Animal>)>;
+
+/// Advances each animal's current activity, moving it around its enclosure
+fn update_behavior(
+    clock: Res<ZooClock>,
+    terrain: Res<Terrain>,
+    animal_data: Res<Assets<AnimalData>>,
+    enclosures: Query<&Enclosure>,
+    props: EnclosureProps,
+    mut animals: Query<(&Animal, &mut AnimalBehavior, &mut Transform), Without<Preview>>,
+) {
+    let delta = clock.delta_seconds();
+
+    for (animal, mut behavior, mut transform) in animals.iter_mut() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+        let Ok(enclosure) = enclosures.get(behavior.enclosure) else { continue };
+
+        let position = transform.translation.xz();
+        let margin = data.size / 2.0;
+        let is_passable = |point| terrain.is_passable(point, data.habitat.mobility);
+
+        let destination = match behavior.activity {
+            Activity::Idle { ref mut remaining }
+            | Activity::Rest { ref mut remaining }
+            | Activity::Linger {
+                ref mut remaining, ..
+            } => {
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    let points_of_interest = props
+                        .iter()
+                        .map(|(entity, transform)| (entity, transform.translation.xz()))
+                        .filter(|&(_, point)| enclosure.contains(point))
+                        .collect::<Vec<_>>();
+
+                    behavior.activity = behavior.choose_activity(
+                        position,
+                        margin,
+                        enclosure,
+                        &points_of_interest,
+                        is_passable,
+                    );
+                }
+
+                continue;
+            }
+
+            Activity::Roam { destination } | Activity::Visit { destination, .. } => destination,
+        };
+
+        // arriving at a destination either lingers there or stands around
+        if position.distance(destination) <= ARRIVAL_DISTANCE {
+            behavior.activity = match behavior.activity {
+                Activity::Visit { target, .. } => Activity::Linger {
+                    remaining: behavior.rng.range(VISIT_DURATION),
+                    target,
+                },
+                _ => Activity::Idle {
+                    remaining: behavior.rng.range(IDLE_DURATION),
+                },
+            };
+
+            continue;
+        }
+
+        let step =
+            (destination - position).clamp_length_max(data.size * WALK_SPEED_PER_SIZE * delta);
+        let next = position + step;
+
+        // fences act as hard boundaries, so give up on any destination that would lead outside of the enclosure
+        let Some(height) = terrain.height_at(next) else { continue };
+        if !enclosure.contains(next) || !is_passable(next) {
+            behavior.activity = Activity::Idle {
+                remaining: behavior.rng.range(IDLE_DURATION),
+            };
+
+            continue;
+        }
+
+        transform.translation = Vec3::new(next.x, height, next.y);
+        transform.rotation = Quat::from_rotation_y(-f32::atan2(step.y, step.x));
+    }
+}
@@ -0,0 +1,41 @@
+use super::AnimalData;
+use crate::objects::{
+    utility::{ColliderMesh, CollisionLayer, RenderGltf, RenderGltfMode},
+    ObjectBundle,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+/// Instance of an animal in the world
+#[derive(Component)]
+pub struct Animal {
+    pub data: Handle<AnimalData>,
+}
+
+impl Animal {
+    /// Object bundle for a permanently placed animal
+    pub(super) fn bundle(
+        data_handle: &Handle<AnimalData>,
+        data: &AnimalData,
+        transform: Transform,
+    ) -> ObjectBundle<Animal> {
+        ObjectBundle {
+            object: Animal {
+                data: data_handle.clone(),
+            },
+            spatial: SpatialBundle {
+                transform,
+                ..default()
+            },
+            gltf: RenderGltf {
+                handle: data.model.clone(),
+                mode: RenderGltfMode::Regular,
+            },
+            collider: ColliderMesh {
+                mesh: data.collider.clone(),
+                rb: RigidBody::KinematicPositionBased,
+                membership: CollisionLayer::Object,
+            },
+        }
+    }
+}
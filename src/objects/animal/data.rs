@@ -0,0 +1,280 @@
+use crate::{
+    terrain::{SurfaceType, WaterMobility},
+    ui::UiDisplay,
+    Currency, CurrencyFormat,
+};
+use bevy::{
+    gltf::GltfMesh,
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+};
+
+pub struct AnimalDataPlugin;
+impl Plugin for AnimalDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<AnimalData>()
+            .init_resource::<AnimalLoader>();
+    }
+}
+
+#[derive(TypeUuid, TypePath)]
+#[uuid = "0f3d8f2e-7a51-4a8e-9c4b-2b1de6a0c7f4"]
+pub struct AnimalData {
+    pub species: String,
+    pub icon: Handle<Image>,
+
+    pub cost: Currency,
+    /// Cost of caring for the animal each day
+    pub upkeep: Currency,
+    /// Stars the zoo needs to be rated before the species can be bought
+    pub rating: f32,
+    /// The model that will be rendered for this animal
+    pub model: Handle<GltfMesh>,
+    /// The single collider mesh for this animal
+    pub collider: Handle<Mesh>,
+    /// Rough length of an adult in meters
+    pub size: f32,
+    /// How excited guests are to see this species, from 0 to 1
+    pub popularity: f32,
+
+    pub diet: Diet,
+    pub social: SocialNeeds,
+    pub habitat: HabitatPreferences,
+    pub life: LifeCycle,
+}
+
+/// What an animal needs to be fed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Diet {
+    Herbivore,
+    Carnivore,
+    Omnivore,
+}
+
+impl Diet {
+    /// Returns true if an animal with this diet is able to eat the given food
+    pub fn eats(self, food: Food) -> bool {
+        match self {
+            Diet::Herbivore => food == Food::Plants,
+            Diet::Carnivore => food == Food::Meat,
+            Diet::Omnivore => true,
+        }
+    }
+}
+
+/// Kind of food that can be put out for animals
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Food {
+    Plants,
+    Meat,
+}
+
+/// Size of the group that an animal is comfortable living in
+#[derive(Clone, Copy, Debug)]
+pub struct SocialNeeds {
+    /// Fewest animals of the same species that should share an enclosure, including itself
+    pub min_group: u32,
+    /// Most animals of the same species that can share an enclosure before it gets crowded
+    pub max_group: u32,
+}
+
+/// How an animal grows up, breeds and ages
+#[derive(Clone, Copy, Debug)]
+pub struct LifeCycle {
+    /// Typical age in years that the animal dies of old age
+    pub lifespan: f32,
+    /// Age in years that the animal becomes an adult and is able to breed
+    pub maturity: f32,
+    /// Years between breeding and giving birth
+    pub gestation: f32,
+    /// Most offspring that can be born at once
+    pub litter_size: u32,
+}
+
+/// Kind of enclosure that an animal is happiest in
+#[derive(Clone, Debug)]
+pub struct HabitatPreferences {
+    /// Preferred fraction of the enclosure's ground covered by each surface type
+    pub surfaces: Vec<(SurfaceType, f32)>,
+    /// Preferred fraction of the enclosure's area covered by water
+    pub water: f32,
+    /// Preferred fraction of the enclosure's area covered by foliage props
+    pub foliage: f32,
+    /// Preferred fraction of the enclosure's area covered by rock props
+    pub rocks: f32,
+    /// How deep of water the animal is able to move through
+    pub mobility: WaterMobility,
+    /// Square meters of space needed by each animal
+    pub area_per_animal: f32,
+    /// Shortest barrier in meters that keeps the animal from getting out
+    pub barrier_height: f32,
+    /// Weakest barrier strength that keeps the animal from breaking out
+    pub barrier_strength: f32,
+}
+
+impl UiDisplay for AnimalData {
+    fn name(&self) -> String {
+        self.species.clone()
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        match self.rating > 0.0 {
+            true => format!("{}, {} stars", self.cost.comma_separated(), self.rating),
+            false => self.cost.comma_separated(),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct AnimalLoader {
+    _animals: Vec<Handle<AnimalData>>,
+}
+
+impl FromWorld for AnimalLoader {
+    fn from_world(world: &mut World) -> Self {
+        // TODO: read from asset file in json or ron format
+        // TODO: replace placeholder rock models once animal models are made
+        let asset_server = world.resource::<AssetServer>();
+        let data = [
+            AnimalData {
+                species: "Zebra".into(),
+                icon: asset_server.load("test.png"),
+                cost: 1500.0,
+                upkeep: 6.0,
+                rating: 0.0,
+                model: asset_server.load("nature/rocks.glb#Mesh3"),
+                collider: asset_server.load("nature/rocks.glb#Mesh3/Primitive0"),
+                size: 2.4,
+                popularity: 0.6,
+                diet: Diet::Herbivore,
+                social: SocialNeeds {
+                    min_group: 3,
+                    max_group: 12,
+                },
+                habitat: HabitatPreferences {
+                    surfaces: vec![(SurfaceType::Grass, 0.7), (SurfaceType::Dirt, 0.3)],
+                    water: 0.05,
+                    foliage: 0.1,
+                    rocks: 0.02,
+                    mobility: WaterMobility::Wading { max_depth: 0.6 },
+                    area_per_animal: 150.0,
+                    barrier_height: 1.5,
+                    barrier_strength: 0.3,
+                },
+                life: LifeCycle {
+                    lifespan: 25.0,
+                    maturity: 3.0,
+                    gestation: 1.0,
+                    litter_size: 1,
+                },
+            },
+            AnimalData {
+                species: "Lion".into(),
+                icon: asset_server.load("test.png"),
+                cost: 3000.0,
+                upkeep: 12.0,
+                rating: 2.0,
+                model: asset_server.load("nature/rocks.glb#Mesh6"),
+                collider: asset_server.load("nature/rocks.glb#Mesh6/Primitive0"),
+                size: 2.5,
+                popularity: 0.9,
+                diet: Diet::Carnivore,
+                social: SocialNeeds {
+                    min_group: 2,
+                    max_group: 6,
+                },
+                habitat: HabitatPreferences {
+                    surfaces: vec![(SurfaceType::Sand, 0.5), (SurfaceType::Grass, 0.5)],
+                    water: 0.05,
+                    foliage: 0.08,
+                    rocks: 0.08,
+                    mobility: WaterMobility::Wading { max_depth: 0.4 },
+                    area_per_animal: 250.0,
+                    barrier_height: 2.0,
+                    barrier_strength: 0.6,
+                },
+                life: LifeCycle {
+                    lifespan: 15.0,
+                    maturity: 3.0,
+                    gestation: 0.3,
+                    litter_size: 3,
+                },
+            },
+            AnimalData {
+                species: "Penguin".into(),
+                icon: asset_server.load("test.png"),
+                cost: 800.0,
+                upkeep: 3.0,
+                rating: 0.5,
+                model: asset_server.load("nature/rocks.glb#Mesh0"),
+                collider: asset_server.load("nature/rocks.glb#Mesh0/Primitive0"),
+                size: 0.7,
+                popularity: 0.7,
+                diet: Diet::Carnivore,
+                social: SocialNeeds {
+                    min_group: 6,
+                    max_group: 30,
+                },
+                habitat: HabitatPreferences {
+                    surfaces: vec![(SurfaceType::Snow, 0.6), (SurfaceType::Rock, 0.4)],
+                    water: 0.4,
+                    foliage: 0.0,
+                    rocks: 0.15,
+                    mobility: WaterMobility::Swimming,
+                    area_per_animal: 15.0,
+                    barrier_height: 1.0,
+                    barrier_strength: 0.2,
+                },
+                life: LifeCycle {
+                    lifespan: 20.0,
+                    maturity: 3.0,
+                    gestation: 0.1,
+                    litter_size: 2,
+                },
+            },
+            AnimalData {
+                species: "Brown Bear".into(),
+                icon: asset_server.load("test.png"),
+                cost: 2500.0,
+                upkeep: 10.0,
+                rating: 1.5,
+                model: asset_server.load("nature/rocks.glb#Mesh1"),
+                collider: asset_server.load("nature/rocks.glb#Mesh1/Primitive0"),
+                size: 2.2,
+                popularity: 0.8,
+                diet: Diet::Omnivore,
+                social: SocialNeeds {
+                    min_group: 1,
+                    max_group: 3,
+                },
+                habitat: HabitatPreferences {
+                    surfaces: vec![(SurfaceType::Grass, 0.6), (SurfaceType::Dirt, 0.4)],
+                    water: 0.15,
+                    foliage: 0.2,
+                    rocks: 0.1,
+                    mobility: WaterMobility::Swimming,
+                    area_per_animal: 300.0,
+                    barrier_height: 2.0,
+                    barrier_strength: 0.8,
+                },
+                life: LifeCycle {
+                    lifespan: 25.0,
+                    maturity: 4.0,
+                    gestation: 0.6,
+                    litter_size: 2,
+                },
+            },
+        ];
+
+        let mut animal_assets = world.resource_mut::<Assets<AnimalData>>();
+        let animals = data.map(|animal| animal_assets.add(animal));
+
+        Self {
+            _animals: animals.into(),
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use super::{
+    behavior::{AnimalBehavior, ARRIVAL_DISTANCE, WALK_SPEED_PER_SIZE},
+    components::Animal,
+    needs::AnimalNeeds,
+    AnimalData,
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::{BarrierData, BarrierFence, BarrierPost, Enclosure, FenceCondition},
+    placement::Preview,
+    rng::SeededRng,
+    terrain::Terrain,
+    zoo::{ZooLogMessage, ZooReputationChange},
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+
+pub struct AnimalEscapePlugin;
+impl Plugin for AnimalEscapePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RecaptureAnimal>()
+            .add_systems(Simulation, (attempt_escapes, roam_escaped))
+            .add_systems(Update, (announce_escapes, on_recapture));
+    }
+}
+
+// constants for animal escapes
+/// Seconds between each check for animals attempting to escape
+const ESCAPE_INTERVAL: f32 = 10.0;
+/// Stress that an animal must be under before it considers escaping, from 0 to 1
+const STRESS_THRESHOLD: f32 = 0.5;
+/// Chance that a completely stressed animal attempts to escape at each check
+const ATTEMPT_CHANCE: f32 = 0.5;
+/// Chance that an attempt succeeds even against a barrier that meets the species' requirements
+const BASE_BREACH_CHANCE: f32 = 0.02;
+/// Condition lost by a fence each time an animal fails to break through it
+const ATTEMPT_DAMAGE: f32 = 0.1;
+/// Reputation lost each time an animal escapes
+const ESCAPE_REPUTATION_PENALTY: f32 = 5.0;
+/// Furthest distance in meters that an escaped animal wanders at once
+const ESCAPED_WANDER_DISTANCE: f32 = 15.0;
+
+/// Marks an animal that has escaped from its enclosure and is roaming free until it is recaptured
+#[derive(Component)]
+pub struct Escaped {
+    /// Enclosure that the animal escaped from and will be returned to
+    pub enclosure: Entity,
+    destination: Option<Vec2>,
+    rng: SeededRng,
+}
+
+/// Event sent to return an escaped animal to its enclosure
+#[derive(Event)]
+pub struct RecaptureAnimal {
+    pub animal: Entity,
+}
+
+/// Fence along the boundary of an enclosure that an animal is trying to get through
+struct EnclosureFence {
+    connection: [Entity; 2],
+    height: f32,
+    /// Strength of the barrier, weakened by how worn down the fence is
+    strength: f32,
+    /// Point along the fence closest to the animal
+    closest: Vec2,
+}
+
+/// System parameter for finding and damaging the fences that keep animals within their enclosures
+#[derive(SystemParam)]
+struct EnclosureFences<'w, 's> {
+    barrier_data: Res<'w, Assets<BarrierData>>,
+    enclosures: Query<'w, 's, &'static Enclosure>,
+    fences: Query<
+        'w,
+        's,
+        (&'static BarrierFence, Option<&'static mut FenceCondition>),
+        Without<Preview>,
+    >,
+    posts: Query<'w, 's, &'static Transform, (With<BarrierPost>, Without<Animal>)>,
+}
+
+impl EnclosureFences<'_, '_> {
+    /// Finds the fence along the enclosure's boundary that is closest to the given point
+    fn nearest(&self, enclosure: Entity, position: Vec2) -> Option<EnclosureFence> {
+        let enclosure = self.enclosures.get(enclosure).ok()?;
+
+        let (fence, condition, closest) = self
+            .fences
+            .iter()
+            // only fences between two of the enclosure's own posts are part of its boundary
+            .filter(|(fence, _)| {
+                fence
+                    .connection
+                    .iter()
+                    .all(|post| enclosure.posts.contains(post))
+            })
+            .filter_map(|(fence, condition)| {
+                let from = self.posts.get(fence.connection[0]).ok()?.translation.xz();
+                let to = self.posts.get(fence.connection[1]).ok()?.translation.xz();
+
+                let edge = to - from;
+                let t = ((position - from).dot(edge) / edge.length_squared()).clamp(0.0, 1.0);
+                Some((fence, condition, from + edge * t))
+            })
+            .min_by(|(.., a), (.., b)| {
+                a.distance_squared(position)
+                    .total_cmp(&b.distance_squared(position))
+            })?;
+
+        let barrier = self.barrier_data.get(&fence.data)?;
+        let condition = condition.map_or(1.0, |condition| condition.condition);
+        Some(EnclosureFence {
+            connection: fence.connection,
+            height: barrier.height,
+            strength: barrier.strength * condition,
+            closest,
+        })
+    }
+
+    /// Wears down the fence between the given posts
+    fn damage(&mut self, connection: [Entity; 2], amount: f32) {
+        let fence = self
+            .fences
+            .iter_mut()
+            .find(|(fence, _)| fence.connection == connection);
+
+        if let Some((_, Some(mut condition))) = fence {
+            condition.condition = (condition.condition - amount).max(0.0);
+        }
+    }
+}
+
+/// Periodically lets stressed animals try to break through the nearest fence of their enclosure
+fn attempt_escapes(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    terrain: Res<Terrain>,
+    animal_data: Res<Assets<AnimalData>>,
+    mut fences: EnclosureFences,
+    mut animals: Query<(
+        Entity,
+        &Animal,
+        &mut AnimalBehavior,
+        &AnimalNeeds,
+        &mut Transform,
+    )>,
+    mut since_check: Local<f32>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < ESCAPE_INTERVAL {
+        return;
+    }
+    *since_check = 0.0;
+
+    for (entity, animal, mut behavior, needs, mut transform) in animals.iter_mut() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        let stress = 1.0 - needs.welfare();
+        if stress < STRESS_THRESHOLD || !behavior.rng.chance(stress * ATTEMPT_CHANCE) {
+            continue;
+        }
+
+        // find the closest point along the enclosure's fences to the animal
+        let position = transform.translation.xz();
+        let Some(fence) = fences.nearest(behavior.enclosure, position) else { continue };
+
+        // barriers that fall short of what the species needs are much easier to get through, as are worn down ones
+        let preferences = &data.habitat;
+        let height_gap = (1.0 - fence.height / preferences.barrier_height).max(0.0);
+        let strength_gap = (preferences.barrier_strength - fence.strength).max(0.0);
+        let breach_chance = (BASE_BREACH_CHANCE + height_gap + strength_gap).min(1.0);
+
+        if !behavior.rng.chance(breach_chance) {
+            // failed attempts still damage the fence
+            fences.damage(fence.connection, ATTEMPT_DAMAGE);
+            continue;
+        }
+
+        // the animal ends up on the other side of the fence
+        let outside = fence.closest + (fence.closest - position).normalize_or_zero() * data.size;
+        let Some(height) = terrain.height_at(outside) else { continue };
+        transform.translation = Vec3::new(outside.x, height, outside.y);
+
+        commands
+            .entity(entity)
+            .remove::<AnimalBehavior>()
+            .insert(Escaped {
+                enclosure: behavior.enclosure,
+                destination: None,
+                rng: behavior.rng.fork(),
+            });
+    }
+}
+
+/// Writes newly escaped animals to the zoo's log, costing the zoo some of its reputation
+fn announce_escapes(
+    animal_data: Res<Assets<AnimalData>>,
+    escaped: Query<&Animal, Added<Escaped>>,
+
+    mut log_messages: EventWriter<ZooLogMessage>,
+    mut reputation_changes: EventWriter<ZooReputationChange>,
+) {
+    for animal in escaped.iter() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        log_messages.send(ZooLogMessage {
+            message: format!("A {} has escaped from its enclosure!", data.species),
+        });
+        reputation_changes.send(ZooReputationChange {
+            amount: -ESCAPE_REPUTATION_PENALTY,
+        });
+    }
+}
+
+/// Moves escaped animals around freely, ignoring fences
+fn roam_escaped(
+    clock: Res<ZooClock>,
+    terrain: Res<Terrain>,
+    animal_data: Res<Assets<AnimalData>>,
+    mut animals: Query<(&Animal, &mut Escaped, &mut Transform)>,
+) {
+    let delta = clock.delta_seconds();
+
+    for (animal, mut escaped, mut transform) in animals.iter_mut() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+        let position = transform.translation.xz();
+
+        let Some(destination) = escaped.destination else {
+            // pick somewhere nearby on the map to wander towards
+            let offset = Vec2::new(escaped.rng.range(-1.0..1.0), escaped.rng.range(-1.0..1.0))
+                * ESCAPED_WANDER_DISTANCE;
+
+            let destination = position + offset;
+            if terrain.is_passable(destination, data.habitat.mobility)
+                && terrain.bounds().contains(destination)
+            {
+                escaped.destination = Some(destination);
+            }
+
+            continue;
+        };
+
+        if position.distance(destination) <= ARRIVAL_DISTANCE {
+            escaped.destination = None;
+            continue;
+        }
+
+        let step =
+            (destination - position).clamp_length_max(data.size * WALK_SPEED_PER_SIZE * delta);
+        let next = position + step;
+
+        let Some(height) = terrain.height_at(next) else { continue };
+        if !terrain.is_passable(next, data.habitat.mobility) {
+            escaped.destination = None;
+            continue;
+        }
+
+        transform.translation = Vec3::new(next.x, height, next.y);
+        transform.rotation = Quat::from_rotation_y(-f32::atan2(step.y, step.x));
+    }
+}
+
+/// Returns recaptured animals to a random spot within the enclosure they escaped from
+fn on_recapture(
+    mut commands: Commands,
+    terrain: Res<Terrain>,
+    animal_data: Res<Assets<AnimalData>>,
+    enclosures: Query<&Enclosure>,
+    mut animals: Query<(&Animal, &mut Escaped, &mut Transform)>,
+
+    mut recaptures: EventReader<RecaptureAnimal>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    for recapture in recaptures.iter() {
+        let Ok((animal, mut escaped, mut transform)) = animals.get_mut(recapture.animal) else {
+            continue;
+        };
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+        let Ok(enclosure) = enclosures.get(escaped.enclosure) else {
+            continue;
+        };
+
+        let mut behavior = AnimalBehavior::new(escaped.enclosure, escaped.rng.fork());
+        let margin = data.size / 2.0;
+        let Some(point) = behavior.random_point(enclosure, margin, |point| {
+            terrain.is_passable(point, data.habitat.mobility)
+        }) else {
+            continue;
+        };
+        let Some(height) = terrain.height_at(point) else { continue };
+
+        transform.translation = Vec3::new(point.x, height, point.y);
+
+        commands
+            .entity(recapture.animal)
+            .remove::<Escaped>()
+            .insert(behavior);
+
+        log_messages.send(ZooLogMessage {
+            message: format!("A {} has been returned to its enclosure", data.species),
+        });
+    }
+}
@@ -0,0 +1,256 @@
+use super::{behavior::AnimalBehavior, components::Animal, AnimalData};
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::{BarrierData, Enclosure, Prop, PropData, PropFeature},
+    placement::Preview,
+    terrain::{SurfaceCoverage, Terrain, WaterMobility},
+    zoo::ZooLogMessage,
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+use std::collections::HashMap;
+
+pub struct AnimalHabitatPlugin;
+impl Plugin for AnimalHabitatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, update_habitats);
+    }
+}
+
+// constants for habitat reports
+/// Seconds between each recalculation of enclosure habitats
+const HABITAT_INTERVAL: f32 = 1.0;
+/// Shortfalls smaller than this fraction of an enclosure aren't worth mentioning
+const MISSING_TOLERANCE: f32 = 0.05;
+/// Water beyond an animal's preference that it will put up with, unless it can swim
+const EXCESS_WATER_TOLERANCE: f32 = 0.25;
+
+/// Measurements of everything within an enclosure that animals care about
+#[derive(Clone, Debug, Default)]
+pub struct HabitatSurvey {
+    /// Area of the enclosure in square meters
+    pub area: f32,
+    pub surfaces: SurfaceCoverage,
+    /// Fraction of the area covered by water
+    pub water: f32,
+    /// Fraction of the area covered by foliage props
+    pub foliage: f32,
+    /// Fraction of the area covered by rock props
+    pub rocks: f32,
+    pub barrier_height: f32,
+    pub barrier_strength: f32,
+}
+
+/// How well an enclosure suits one of the species living within it
+#[derive(Clone, Debug)]
+pub struct HabitatReport {
+    pub species: Handle<AnimalData>,
+    /// Overall suitability from 0 to 1
+    pub score: f32,
+    /// Descriptions of what the enclosure is missing, such as "needs 20% more water"
+    pub missing: Vec<String>,
+}
+
+impl HabitatReport {
+    /// Compares the survey of an enclosure against the preferences of a species with the given number of residents
+    fn new(
+        species: Handle<AnimalData>,
+        data: &AnimalData,
+        survey: &HabitatSurvey,
+        residents: u32,
+    ) -> Self {
+        let preferences = &data.habitat;
+        let mut missing = Vec::new();
+
+        // scores how close an actual fraction is to a preferred one, noting any shortfall
+        let mut fraction_score = |preferred: f32, actual: f32, name: &str| {
+            if preferred - actual >= MISSING_TOLERANCE {
+                missing.push(format!(
+                    "needs {:.0}% more {}",
+                    (preferred - actual) * 100.0,
+                    name
+                ));
+            }
+
+            match preferred > 0.0 {
+                true => (actual / preferred).min(1.0),
+                false => 1.0,
+            }
+        };
+
+        let surfaces = preferences
+            .surfaces
+            .iter()
+            .map(|&(surface, preferred)| {
+                let name = surface.name().to_lowercase();
+                fraction_score(preferred, survey.surfaces.fraction(surface), &name)
+            })
+            .collect::<Vec<_>>();
+        let surfaces = match surfaces.is_empty() {
+            true => 1.0,
+            false => surfaces.iter().sum::<f32>() / surfaces.len() as f32,
+        };
+
+        let mut water = fraction_score(preferences.water, survey.water, "water");
+        let foliage = fraction_score(preferences.foliage, survey.foliage, "foliage");
+        let rocks = fraction_score(preferences.rocks, survey.rocks, "rock cover");
+
+        // animals that can't swim don't want an enclosure that is mostly water
+        let excess_water = survey.water - preferences.water;
+        if preferences.mobility != WaterMobility::Swimming && excess_water > EXCESS_WATER_TOLERANCE
+        {
+            missing.push(format!("has {:.0}% too much water", excess_water * 100.0));
+            water *= 1.0 - excess_water;
+        }
+
+        let needed_area = preferences.area_per_animal * residents as f32;
+        let area = match survey.area < needed_area {
+            true => {
+                missing.push(format!(
+                    "needs {:.0} m² more space",
+                    needed_area - survey.area
+                ));
+                survey.area / needed_area
+            }
+            false => 1.0,
+        };
+
+        let mut barrier: f32 = 1.0;
+        if survey.barrier_height < preferences.barrier_height {
+            missing.push(format!(
+                "needs a barrier at least {:.1} m tall",
+                preferences.barrier_height
+            ));
+            barrier -= 0.5;
+        }
+        if survey.barrier_strength < preferences.barrier_strength {
+            missing.push("needs a stronger barrier".into());
+            barrier -= 0.5;
+        }
+
+        let scores = [surfaces, water, foliage, rocks, area, barrier.max(0.0)];
+
+        Self {
+            species,
+            score: scores.iter().sum::<f32>() / scores.len() as f32,
+            missing,
+        }
+    }
+}
+
+/// Habitat reports for each species living within an enclosure
+#[derive(Component, Clone, Debug, Default)]
+pub struct EnclosureHabitat {
+    pub reports: Vec<HabitatReport>,
+}
+
+impl EnclosureHabitat {
+    /// Return the report for the given species, if any of them live in the enclosure
+    pub fn report(&self, species: &Handle<AnimalData>) -> Option<&HabitatReport> {
+        self.reports
+            .iter()
+            .find(|report| &report.species == species)
+    }
+}
+
+/// System parameter with everything needed to survey enclosures and report on how they suit each species
+#[derive(SystemParam)]
+struct HabitatSurveyor<'w, 's> {
+    terrain: Res<'w, Terrain>,
+    animal_data: Res<'w, Assets<AnimalData>>,
+    barrier_data: Res<'w, Assets<BarrierData>>,
+    prop_data: Res<'w, Assets<PropData>>,
+    props: Query<'w, 's, (&'static Prop, &'static Transform), Without<Preview>>,
+}
+
+impl HabitatSurveyor<'_, '_> {
+    /// Measures everything within the enclosure that animals care about
+    fn survey(&self, enclosure: &Enclosure) -> HabitatSurvey {
+        let area = enclosure.polygon.area();
+        let barrier = self.barrier_data.get(&enclosure.barrier);
+
+        // sum up the ground covered by each kind of nature prop
+        let mut foliage = 0.0;
+        let mut rocks = 0.0;
+        for (prop, transform) in self.props.iter() {
+            let Some(data) = self.prop_data.get(&prop.data) else { continue };
+            if !enclosure.contains(transform.translation.xz()) {
+                continue;
+            }
+
+            match data.feature {
+                PropFeature::Foliage => foliage += data.coverage,
+                PropFeature::Rock => rocks += data.coverage,
+                _ => (),
+            }
+        }
+
+        HabitatSurvey {
+            area,
+            surfaces: self.terrain.surface_coverage(&enclosure.polygon),
+            water: self.terrain.water_report(&enclosure.polygon).fraction,
+            foliage: (foliage / area).min(1.0),
+            rocks: (rocks / area).min(1.0),
+            barrier_height: barrier.map_or(0.0, |barrier| barrier.height),
+            barrier_strength: barrier.map_or(0.0, |barrier| barrier.strength),
+        }
+    }
+}
+
+/// Periodically surveys every enclosure and reports how well it suits each of its resident species
+///
+/// Anything newly found missing from an enclosure is written to the zoo's log
+fn update_habitats(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    surveyor: HabitatSurveyor,
+    enclosures: Query<(Entity, &Enclosure, Option<&EnclosureHabitat>)>,
+    animals: Query<(&Animal, &AnimalBehavior), Without<Preview>>,
+    mut since_update: Local<f32>,
+
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    *since_update += clock.delta_seconds();
+    if *since_update < HABITAT_INTERVAL {
+        return;
+    }
+    *since_update = 0.0;
+
+    // count residents of each species within each enclosure
+    let mut residents = HashMap::<Entity, HashMap<Handle<AnimalData>, u32>>::new();
+    for (animal, behavior) in animals.iter() {
+        *residents
+            .entry(behavior.enclosure)
+            .or_default()
+            .entry(animal.data.clone())
+            .or_default() += 1;
+    }
+
+    for (entity, enclosure, habitat) in enclosures.iter() {
+        let survey = surveyor.survey(enclosure);
+
+        let reports = residents
+            .get(&entity)
+            .into_iter()
+            .flatten()
+            .filter_map(|(species, &count)| {
+                let data = surveyor.animal_data.get(species)?;
+                Some(HabitatReport::new(species.clone(), data, &survey, count))
+            })
+            .collect::<Vec<_>>();
+
+        // only mention an enclosure's shortfalls when more of them turn up, to avoid repeating the same ones
+        for report in reports.iter() {
+            let Some(data) = surveyor.animal_data.get(&report.species) else { continue };
+            let previous = habitat.and_then(|habitat| habitat.report(&report.species));
+            if report.missing.len() <= previous.map_or(0, |previous| previous.missing.len()) {
+                continue;
+            }
+
+            log_messages.send(ZooLogMessage {
+                message: format!("{} enclosure {}", data.species, report.missing.join(", ")),
+            });
+        }
+
+        commands.entity(entity).insert(EnclosureHabitat { reports });
+    }
+}
@@ -0,0 +1,335 @@
+use super::{
+    behavior::AnimalBehavior, components::Animal, lifecycle::AnimalLife, needs::AnimalNeeds,
+    AnimalData,
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    litter::{Litter, LitterKind},
+    objects::Enclosure,
+    placement::Preview,
+    zoo::{Zoo, ZooBalanceChange, ZooLogMessage},
+    Currency,
+};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+pub struct AnimalHealthPlugin;
+impl Plugin for AnimalHealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TreatAnimal>()
+            .add_systems(Simulation, (update_health, spread_disease).chain())
+            .add_systems(Update, on_treat);
+    }
+}
+
+// constants for animal health
+/// Welfare above which an animal's health recovers, and below which it suffers
+const HEALTHY_WELFARE: f32 = 0.5;
+/// Fraction of health gained or lost each second for each unit of welfare away from healthy
+const HEALTH_RATE: f32 = 1.0 / 300.0;
+/// Seconds between each check for animals falling ill and passing on illnesses
+const DISEASE_INTERVAL: f32 = 5.0;
+/// Chance at each check that an animal in poor health falls ill on its own
+const OUTBREAK_CHANCE: f32 = 0.01;
+/// Multiplier on the chance of catching an illness from an animal in an adjacent enclosure
+const ADJACENT_SPREAD: f32 = 0.25;
+/// Seconds that an animal can't catch another illness after recovering
+const IMMUNITY_DURATION: f32 = 120.0;
+/// Pieces of waste within an enclosure that make it completely filthy
+const FILTHY_WASTE: f32 = 10.0;
+/// Fraction of health lost each second while living in a completely filthy enclosure
+const DIRTY_DAMAGE: f32 = 1.0 / 600.0;
+/// Multiplier on how fast an illness goes away once treated by a vet
+const TREATED_RECOVERY: f32 = 8.0;
+
+/// Contagious illnesses that animals can catch
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Disease {
+    Flu,
+    Parasites,
+    Mange,
+}
+
+impl Disease {
+    pub const ALL: [Disease; 3] = [Disease::Flu, Disease::Parasites, Disease::Mange];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Disease::Flu => "Flu",
+            Disease::Parasites => "Parasites",
+            Disease::Mange => "Mange",
+        }
+    }
+
+    /// Chance at each check of passing the illness on to another animal in the same enclosure
+    pub fn contagiousness(&self) -> f32 {
+        match self {
+            Disease::Flu => 0.3,
+            Disease::Parasites => 0.1,
+            Disease::Mange => 0.15,
+        }
+    }
+
+    /// Fraction of health lost each second while ill
+    pub fn damage(&self) -> f32 {
+        match self {
+            Disease::Flu => 1.0 / 400.0,
+            Disease::Parasites => 1.0 / 250.0,
+            Disease::Mange => 1.0 / 600.0,
+        }
+    }
+
+    /// Fraction of the illness that goes away each second on its own
+    pub fn recovery(&self) -> f32 {
+        match self {
+            Disease::Flu => 1.0 / 200.0,
+            Disease::Parasites => 1.0 / 900.0,
+            Disease::Mange => 1.0 / 600.0,
+        }
+    }
+
+    /// Cost for a vet to treat the illness
+    pub fn treatment_cost(&self) -> Currency {
+        match self {
+            Disease::Flu => 150.0,
+            Disease::Parasites => 300.0,
+            Disease::Mange => 200.0,
+        }
+    }
+}
+
+/// Illness that an animal is currently suffering from
+#[derive(Clone, Copy, Debug)]
+pub struct Illness {
+    pub disease: Disease,
+    /// How much of the illness is left, going away once it reaches 0
+    pub severity: f32,
+    /// Seconds since the animal fell ill
+    pub duration: f32,
+    /// Whether a vet has treated the illness
+    pub treated: bool,
+}
+
+/// Physical health of an animal
+#[derive(Component, Clone, Debug)]
+pub struct AnimalHealth {
+    /// Health from 0 to 1, the animal dies once it reaches 0
+    pub health: f32,
+    pub illness: Option<Illness>,
+    /// Seconds remaining that the animal can't catch another illness
+    pub immunity: f32,
+}
+
+impl Default for AnimalHealth {
+    fn default() -> Self {
+        Self {
+            health: 1.0,
+            illness: None,
+            immunity: 0.0,
+        }
+    }
+}
+
+impl AnimalHealth {
+    /// Returns true if the animal is able to catch an illness
+    pub fn is_susceptible(&self) -> bool {
+        self.illness.is_none() && self.immunity <= 0.0
+    }
+
+    fn fall_ill(&mut self, disease: Disease) {
+        self.illness = Some(Illness {
+            disease,
+            severity: 1.0,
+            duration: 0.0,
+            treated: false,
+        });
+    }
+}
+
+/// Event sent for a vet to treat an ill animal, charging the zoo for the treatment
+#[derive(Event)]
+pub struct TreatAnimal {
+    pub animal: Entity,
+}
+
+/// Improves or worsens health based on welfare and how clean the enclosure is kept, and progresses any illness
+fn update_health(
+    clock: Res<ZooClock>,
+    litter: Query<&Litter>,
+    mut animals: Query<(&AnimalNeeds, &mut AnimalHealth, Option<&AnimalBehavior>)>,
+) {
+    let delta = clock.delta_seconds();
+
+    // count the waste left within each enclosure
+    let mut waste = HashMap::<Entity, u32>::new();
+    for litter in litter
+        .iter()
+        .filter(|litter| litter.kind == LitterKind::Waste)
+    {
+        if let Some(enclosure) = litter.enclosure {
+            *waste.entry(enclosure).or_default() += 1;
+        }
+    }
+
+    for (needs, mut health, behavior) in animals.iter_mut() {
+        let mut change = (needs.welfare() - HEALTHY_WELFARE) * HEALTH_RATE * delta;
+
+        // living among waste slowly wears animals down
+        let dirtiness = behavior
+            .and_then(|behavior| waste.get(&behavior.enclosure))
+            .map_or(0.0, |&count| (count as f32 / FILTHY_WASTE).min(1.0));
+        change -= DIRTY_DAMAGE * dirtiness * delta;
+        health.immunity = (health.immunity - delta).max(0.0);
+
+        if let Some(illness) = &mut health.illness {
+            let recovery = match illness.treated {
+                true => illness.disease.recovery() * TREATED_RECOVERY,
+                false => illness.disease.recovery(),
+            };
+
+            illness.severity -= recovery * delta;
+            illness.duration += delta;
+            change = change.min(0.0) - illness.disease.damage() * illness.severity * delta;
+        }
+
+        // recovered animals are immune for a while
+        if health
+            .illness
+            .is_some_and(|illness| illness.severity <= 0.0)
+        {
+            health.illness = None;
+            health.immunity = IMMUNITY_DURATION;
+        }
+
+        health.health = (health.health + change).clamp(0.0, 1.0);
+    }
+}
+
+/// Periodically lets animals in poor health fall ill, and spreads illnesses to nearby animals
+fn spread_disease(
+    clock: Res<ZooClock>,
+    animal_data: Res<Assets<AnimalData>>,
+    enclosures: Query<(Entity, &Enclosure)>,
+    mut animals: Query<
+        (&Animal, &AnimalBehavior, &mut AnimalLife, &mut AnimalHealth),
+        Without<Preview>,
+    >,
+    mut since_check: Local<f32>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < DISEASE_INTERVAL {
+        return;
+    }
+    *since_check = 0.0;
+
+    // enclosures are adjacent when they share a post of their boundaries
+    let mut adjacent = HashMap::<Entity, HashSet<Entity>>::new();
+    for (a, enclosure_a) in enclosures.iter() {
+        for (b, enclosure_b) in enclosures.iter() {
+            if a != b
+                && enclosure_a
+                    .posts
+                    .iter()
+                    .any(|post| enclosure_b.posts.contains(post))
+            {
+                adjacent.entry(a).or_default().insert(b);
+            }
+        }
+    }
+
+    // gather every illness currently spreading, by enclosure
+    let mut spreading = HashMap::<Entity, Vec<Disease>>::new();
+    for (_, behavior, _, health) in animals.iter() {
+        if let Some(illness) = health.illness {
+            spreading
+                .entry(behavior.enclosure)
+                .or_default()
+                .push(illness.disease);
+        }
+    }
+
+    for (animal, behavior, mut life, mut health) in animals.iter_mut() {
+        if !health.is_susceptible() {
+            continue;
+        }
+
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        // hardier animals are less likely to catch anything
+        let resistance = life.traits.hardiness;
+
+        // animals can catch illnesses from their own enclosure, and less often from adjacent ones
+        let neighbors = adjacent.get(&behavior.enclosure).into_iter().flatten();
+        let sources = std::iter::once((behavior.enclosure, 1.0))
+            .chain(neighbors.map(|&neighbor| (neighbor, ADJACENT_SPREAD)));
+
+        let mut caught = None;
+        for (enclosure, exposure) in sources {
+            for &disease in spreading.get(&enclosure).into_iter().flatten() {
+                let chance = disease.contagiousness() * exposure / resistance;
+                if caught.is_none() && life.rng.chance(chance) {
+                    caught = Some(disease);
+                }
+            }
+        }
+
+        // otherwise, animals in poor health can fall ill on their own
+        if caught.is_none() && life.rng.chance(OUTBREAK_CHANCE * (1.0 - health.health)) {
+            caught = life.rng.pick(&Disease::ALL).copied();
+        }
+
+        if let Some(disease) = caught {
+            health.fall_ill(disease);
+
+            log_messages.send(ZooLogMessage {
+                message: format!("A {} has fallen ill with {}", data.species, disease.name()),
+            });
+        }
+    }
+}
+
+/// Treats animals' illnesses if the zoo can afford it
+fn on_treat(
+    zoo: Res<Zoo>,
+    animal_data: Res<Assets<AnimalData>>,
+    mut animals: Query<(&Animal, &mut AnimalHealth)>,
+
+    mut treatments: EventReader<TreatAnimal>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    // track spending within this frame, since balance changes are only applied later
+    let mut balance = zoo.balance();
+
+    for treatment in treatments.iter() {
+        let Ok((animal, mut health)) = animals.get_mut(treatment.animal) else { continue };
+        let Some(illness) = &mut health.illness else { continue };
+        if illness.treated {
+            continue;
+        }
+
+        let cost = illness.disease.treatment_cost();
+        if balance < cost {
+            continue;
+        }
+
+        illness.treated = true;
+        balance -= cost;
+        balance_changes.send(ZooBalanceChange {
+            amount: -cost,
+            category: LedgerCategory::Veterinary,
+        });
+
+        if let Some(data) = animal_data.get(&animal.data) {
+            log_messages.send(ZooLogMessage {
+                message: format!(
+                    "A vet treated a {} for {}",
+                    data.species,
+                    illness.disease.name()
+                ),
+            });
+        }
+    }
+}
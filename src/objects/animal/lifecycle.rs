@@ -0,0 +1,515 @@
+use super::{
+    behavior::AnimalBehavior, components::Animal, health::AnimalHealth, needs::AnimalNeeds,
+    AnimalData,
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    placement::Preview,
+    rng::SeededRng,
+    terrain::SeatOnTerrain,
+    zoo::{ZooLogMessage, ZooReputationChange},
+};
+use bevy::prelude::*;
+use std::{collections::HashMap, ops::Range};
+
+pub struct AnimalLifecyclePlugin;
+impl Plugin for AnimalLifecyclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PopulationEvent>()
+            .init_resource::<PopulationRecords>()
+            .add_systems(
+                Simulation,
+                (age_animals, update_population, record_population).chain(),
+            );
+    }
+}
+
+// constants for animal lifecycles
+/// Seconds between each check for breeding, births and deaths
+const POPULATION_INTERVAL: f32 = 5.0;
+/// Fraction of its adult size that a newborn animal starts at
+const NEWBORN_SCALE: f32 = 0.4;
+/// Welfare that both parents need to be above for them to breed
+const BREEDING_WELFARE: f32 = 0.7;
+/// Chance at each check that a happy adult female with a mate breeds
+const BREEDING_CHANCE: f32 = 0.05;
+/// Welfare below which animals are at risk of dying from poor care
+const NEGLECT_WELFARE: f32 = 0.15;
+/// Chance at each check that a neglected animal dies
+const NEGLECT_DEATH_CHANCE: f32 = 0.05;
+/// Fraction of an animal's lifespan past it over which its chance of dying of old age climbs to certain
+const OLD_AGE_SPAN: f32 = 0.2;
+/// Reputation lost each time an animal dies from poor care
+const NEGLECT_REPUTATION_PENALTY: f32 = 3.0;
+
+// constants for animal traits
+/// Range that traits of animals bought from outside of the zoo fall within
+const BOUGHT_TRAIT_RANGE: Range<f32> = 0.85..1.15;
+/// Largest random change to a trait when it is passed on to offspring
+const TRAIT_MUTATION: f32 = 0.05;
+/// Range that traits are always kept within
+const TRAIT_LIMITS: (f32, f32) = (0.5, 1.5);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sex {
+    Male,
+    Female,
+}
+
+/// Inheritable multipliers that make each animal slightly different from others of its species
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimalTraits {
+    /// Multiplier on the size of the species
+    pub size: f32,
+    /// Multiplier on the chance of breeding
+    pub fertility: f32,
+    /// Multiplier on lifespan and resistance to illness
+    pub hardiness: f32,
+    /// Multiplier on how exciting the animal is for guests to see
+    pub appeal: f32,
+}
+
+impl AnimalTraits {
+    /// Traits for an animal that was born outside of the zoo
+    pub(super) fn random(rng: &mut SeededRng) -> Self {
+        Self {
+            size: rng.range(BOUGHT_TRAIT_RANGE),
+            fertility: rng.range(BOUGHT_TRAIT_RANGE),
+            hardiness: rng.range(BOUGHT_TRAIT_RANGE),
+            appeal: rng.range(BOUGHT_TRAIT_RANGE),
+        }
+    }
+
+    /// Traits for offspring, each being the average of its parents' with a small random change
+    fn inherit(mother: &Self, father: &Self, rng: &mut SeededRng) -> Self {
+        let mut inherit = |a: f32, b: f32| {
+            let mutation = rng.range(-TRAIT_MUTATION..TRAIT_MUTATION);
+            ((a + b) / 2.0 + mutation).clamp(TRAIT_LIMITS.0, TRAIT_LIMITS.1)
+        };
+
+        Self {
+            size: inherit(mother.size, father.size),
+            fertility: inherit(mother.fertility, father.fertility),
+            hardiness: inherit(mother.hardiness, father.hardiness),
+            appeal: inherit(mother.appeal, father.appeal),
+        }
+    }
+}
+
+/// Offspring being carried by a female animal
+#[derive(Clone, Copy, Debug)]
+pub struct Pregnancy {
+    /// Years remaining until birth
+    pub remaining: f32,
+    pub father: AnimalTraits,
+}
+
+/// Age, sex and inherited traits of an animal
+#[derive(Component)]
+pub struct AnimalLife {
+    /// Age in years
+    pub age: f32,
+    pub sex: Sex,
+    pub traits: AnimalTraits,
+    pub pregnancy: Option<Pregnancy>,
+    pub(super) rng: SeededRng,
+}
+
+impl AnimalLife {
+    pub(super) fn new(age: f32, sex: Sex, traits: AnimalTraits, rng: &mut SeededRng) -> Self {
+        Self {
+            age,
+            sex,
+            traits,
+            pregnancy: None,
+            rng: rng.fork(),
+        }
+    }
+
+    /// Life of an adult animal that was bought from outside of the zoo
+    pub(super) fn bought(data: &AnimalData, rng: &mut SeededRng) -> Self {
+        let age = data.life.maturity + rng.range(0.0..data.life.lifespan * 0.25);
+        let sex = Self::random_sex(rng);
+        let traits = AnimalTraits::random(rng);
+
+        Self::new(age, sex, traits, rng)
+    }
+
+    /// Life of an animal that was just born to the given parents
+    fn born(mother: &AnimalTraits, father: &AnimalTraits, rng: &mut SeededRng) -> Self {
+        let sex = Self::random_sex(rng);
+        let traits = AnimalTraits::inherit(mother, father, rng);
+
+        Self::new(0.0, sex, traits, rng)
+    }
+
+    pub(super) fn random_sex(rng: &mut SeededRng) -> Sex {
+        match rng.chance(0.5) {
+            true => Sex::Male,
+            false => Sex::Female,
+        }
+    }
+
+    /// Returns true if the animal is old enough to breed
+    pub fn is_adult(&self, data: &AnimalData) -> bool {
+        self.age >= data.life.maturity
+    }
+
+    /// Age in years that this animal is expected to die of old age
+    pub fn lifespan(&self, data: &AnimalData) -> f32 {
+        data.life.lifespan * self.traits.hardiness
+    }
+
+    /// Scale of the animal's model, growing from birth until adulthood
+    fn scale(&self, data: &AnimalData) -> f32 {
+        let growth = (self.age / data.life.maturity).min(1.0);
+        self.traits.size * (NEWBORN_SCALE + (1.0 - NEWBORN_SCALE) * growth)
+    }
+}
+
+/// Why an animal died
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeathCause {
+    OldAge,
+    PoorCare,
+    Illness,
+}
+
+/// Event sent whenever animals are born into or die within the zoo
+#[derive(Event, Clone, Debug)]
+pub enum PopulationEvent {
+    Born {
+        /// Every newborn from a single birth
+        litter: Vec<Entity>,
+        species: Handle<AnimalData>,
+    },
+    Died {
+        species: Handle<AnimalData>,
+        cause: DeathCause,
+    },
+}
+
+/// Running totals of every animal born, lost or traded by the zoo
+#[derive(Resource, Default)]
+pub struct PopulationRecords {
+    pub births: u32,
+    pub deaths: u32,
+    pub sold: u32,
+    /// Animals released into the wild as part of conservation efforts
+    pub released: u32,
+}
+
+/// Ages every animal, growing young animals and progressing pregnancies
+fn age_animals(
+    clock: Res<ZooClock>,
+    animal_data: Res<Assets<AnimalData>>,
+    mut animals: Query<(&Animal, &mut AnimalLife, &mut Transform), Without<Preview>>,
+) {
+    let years = clock.delta_years();
+
+    for (animal, mut life, mut transform) in animals.iter_mut() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        life.age += years;
+        if let Some(pregnancy) = &mut life.pregnancy {
+            pregnancy.remaining -= years;
+        }
+
+        let scale = Vec3::splat(life.scale(data));
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+    }
+}
+
+/// Animals that could breed, give birth or die, along with what decides whether they do
+type PopulationAnimals<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Animal,
+        &'static mut AnimalLife,
+        &'static AnimalNeeds,
+        &'static AnimalHealth,
+        Option<&'static AnimalBehavior>,
+        &'static Transform,
+    ),
+    Without<Preview>,
+>;
+
+/// Periodically lets animals breed, give birth and die
+fn update_population(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    animal_data: Res<Assets<AnimalData>>,
+    mut animals: PopulationAnimals,
+    mut since_update: Local<f32>,
+
+    mut population_events: EventWriter<PopulationEvent>,
+) {
+    *since_update += clock.delta_seconds();
+    if *since_update < POPULATION_INTERVAL {
+        return;
+    }
+    *since_update = 0.0;
+
+    // gather potential fathers and group sizes for each species within each enclosure
+    let mut fathers = HashMap::<(Entity, Handle<AnimalData>), Vec<AnimalTraits>>::new();
+    let mut group_sizes = HashMap::<(Entity, Handle<AnimalData>), u32>::new();
+    for (_, animal, life, needs, _, behavior, _) in animals.iter() {
+        let Some(behavior) = behavior else { continue };
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+        let key = (behavior.enclosure, animal.data.clone());
+
+        *group_sizes.entry(key.clone()).or_default() += 1;
+
+        if life.sex == Sex::Male && life.is_adult(data) && needs.welfare() >= BREEDING_WELFARE {
+            fathers.entry(key).or_default().push(life.traits);
+        }
+    }
+
+    for (entity, animal, mut life, needs, health, behavior, transform) in animals.iter_mut() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        // death from old age becomes more likely the further past its lifespan an animal gets
+        let lifespan = life.lifespan(data);
+        let old_age_chance = (life.age - lifespan) / (lifespan * OLD_AGE_SPAN);
+
+        let cause = match (
+            life.rng.chance(old_age_chance),
+            needs.welfare() < NEGLECT_WELFARE && life.rng.chance(NEGLECT_DEATH_CHANCE),
+        ) {
+            _ if health.health <= 0.0 => Some(DeathCause::Illness),
+            (true, _) => Some(DeathCause::OldAge),
+            (_, true) => Some(DeathCause::PoorCare),
+            _ => None,
+        };
+
+        if let Some(cause) = cause {
+            commands.entity(entity).despawn_recursive();
+            population_events.send(PopulationEvent::Died {
+                species: animal.data.clone(),
+                cause,
+            });
+
+            continue;
+        }
+
+        // only animals within an enclosure breed and give birth
+        let Some(behavior) = behavior else { continue };
+        let key = (behavior.enclosure, animal.data.clone());
+
+        match life.pregnancy {
+            Some(pregnancy) if pregnancy.remaining <= 0.0 => {
+                life.pregnancy = None;
+
+                let litter_size =
+                    1 + (life.rng.next_u64() % data.life.litter_size.max(1) as u64) as u32;
+                let mut litter = Vec::new();
+                for _ in 0..litter_size {
+                    let mother = life.traits;
+                    let offspring = AnimalLife::born(&mother, &pregnancy.father, &mut life.rng);
+
+                    // newborns appear right beside their mother
+                    let offset =
+                        Vec3::new(life.rng.range(-1.0..1.0), 0.0, life.rng.range(-1.0..1.0))
+                            * data.size
+                            / 2.0;
+                    let transform = Transform::from_translation(transform.translation + offset)
+                        .with_scale(Vec3::splat(offspring.scale(data)));
+
+                    let behavior = AnimalBehavior::new(behavior.enclosure, life.rng.fork());
+                    let newborn = commands
+                        .spawn((
+                            Animal::bundle(&animal.data, data, transform),
+                            behavior,
+                            AnimalNeeds::default(),
+                            AnimalHealth::default(),
+                            offspring,
+                            SeatOnTerrain,
+                        ))
+                        .id();
+                    litter.push(newborn);
+                }
+
+                population_events.send(PopulationEvent::Born {
+                    litter,
+                    species: animal.data.clone(),
+                });
+            }
+
+            // healthy adult females can breed with a healthy adult male sharing the enclosure
+            None if life.sex == Sex::Female
+                && life.is_adult(data)
+                && needs.welfare() >= BREEDING_WELFARE
+                && group_sizes.get(&key).copied().unwrap_or(0) < data.social.max_group =>
+            {
+                let Some(mates) = fathers.get(&key) else { continue };
+                let breeding_chance = BREEDING_CHANCE * life.traits.fertility;
+                if !life.rng.chance(breeding_chance) {
+                    continue;
+                }
+
+                let Some(&father) = life.rng.pick(mates) else { continue };
+                life.pregnancy = Some(Pregnancy {
+                    remaining: data.life.gestation,
+                    father,
+                });
+            }
+
+            _ => (),
+        }
+    }
+}
+
+/// Keeps the zoo's records and log up to date with every birth and death
+fn record_population(
+    animal_data: Res<Assets<AnimalData>>,
+    mut records: ResMut<PopulationRecords>,
+
+    mut population_events: EventReader<PopulationEvent>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+    mut reputation_changes: EventWriter<ZooReputationChange>,
+) {
+    for event in population_events.iter() {
+        match event {
+            PopulationEvent::Born { litter, species } => {
+                let Some(data) = animal_data.get(species) else { continue };
+                records.births += litter.len() as u32;
+
+                log_messages.send(ZooLogMessage {
+                    message: match litter.len() {
+                        1 => format!("A baby {} has been born!", data.species),
+                        count => format!("{} baby {}s have been born!", count, data.species),
+                    },
+                });
+            }
+
+            PopulationEvent::Died { species, cause } => {
+                let Some(data) = animal_data.get(species) else { continue };
+                records.deaths += 1;
+
+                let message = match cause {
+                    DeathCause::OldAge => format!("A {} has died of old age", data.species),
+                    DeathCause::PoorCare => {
+                        reputation_changes.send(ZooReputationChange {
+                            amount: -NEGLECT_REPUTATION_PENALTY,
+                        });
+                        format!("A {} has died from poor care", data.species)
+                    }
+                    DeathCause::Illness => format!("A {} has died of illness", data.species),
+                };
+
+                log_messages.send(ZooLogMessage { message });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        objects::animal::data::{Diet, HabitatPreferences, LifeCycle, SocialNeeds},
+        terrain::WaterMobility,
+    };
+
+    fn test_species() -> AnimalData {
+        AnimalData {
+            species: "Test".to_string(),
+            icon: Handle::default(),
+            cost: Default::default(),
+            upkeep: Default::default(),
+            rating: 0.0,
+            model: Handle::default(),
+            collider: Handle::default(),
+            size: 1.0,
+            popularity: 1.0,
+            diet: Diet::Herbivore,
+            social: SocialNeeds {
+                min_group: 1,
+                max_group: 4,
+            },
+            habitat: HabitatPreferences {
+                surfaces: Vec::new(),
+                water: 0.0,
+                foliage: 0.0,
+                rocks: 0.0,
+                mobility: WaterMobility::Wading { max_depth: 0.5 },
+                area_per_animal: 10.0,
+                barrier_height: 1.0,
+                barrier_strength: 0.5,
+            },
+            life: LifeCycle {
+                lifespan: 20.0,
+                maturity: 2.0,
+                gestation: 0.5,
+                litter_size: 4,
+            },
+        }
+    }
+
+    /// Lets a mother that is due give birth, returning the sex and traits of each newborn
+    fn births_from_seed(seed: u64) -> Vec<(Sex, AnimalTraits)> {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<AnimalData>()
+            .add_event::<PopulationEvent>()
+            .init_resource::<ZooClock>()
+            .add_systems(Update, update_population);
+
+        let species = app
+            .world
+            .resource_mut::<Assets<AnimalData>>()
+            .add(test_species());
+        let data = test_species();
+
+        let mut rng = SeededRng::new(seed);
+        let life = AnimalLife {
+            age: 5.0,
+            sex: Sex::Female,
+            traits: AnimalTraits::random(&mut rng),
+            pregnancy: Some(Pregnancy {
+                remaining: 0.0,
+                father: AnimalTraits::random(&mut rng),
+            }),
+            rng: rng.fork(),
+        };
+
+        let enclosure = app.world.spawn_empty().id();
+        let mother = app
+            .world
+            .spawn((
+                Animal::bundle(&species, &data, Transform::default()),
+                AnimalBehavior::new(enclosure, rng.fork()),
+                AnimalNeeds::default(),
+                AnimalHealth::default(),
+                life,
+            ))
+            .id();
+
+        // run for long enough that the population is updated exactly once
+        let clock = ZooClock::default();
+        let steps = (POPULATION_INTERVAL / clock.delta_seconds()).ceil() as usize + 1;
+        for _ in 0..steps {
+            app.update();
+        }
+
+        let mut newborns = app.world.query::<(Entity, &AnimalLife)>();
+        newborns
+            .iter(&app.world)
+            .filter(|(entity, _)| *entity != mother)
+            .map(|(_, life)| (life.sex, life.traits))
+            .collect()
+    }
+
+    #[test]
+    fn births_are_deterministic_for_a_seed() {
+        let first = births_from_seed(7);
+        let second = births_from_seed(7);
+
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,322 @@
+use super::{
+    components::Animal,
+    lifecycle::{AnimalLife, AnimalTraits, PopulationRecords, Sex},
+    placement::spawn_animal_preview,
+    AnimalData,
+};
+use crate::{
+    camera::CursorRaycast,
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    objects::utility::CollisionLayer,
+    placement::{PlacePreview, Preview, PreviewData},
+    rng::SeededRng,
+    ui::UiDisplay,
+    zoo::{ZooBalanceChange, ZooLogMessage, ZooReputationChange},
+    Currency, CurrencyFormat,
+};
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+pub struct AnimalMarketPlugin;
+impl Plugin for AnimalMarketPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AnimalMarket>()
+            .init_resource::<AnimalTradeTools>()
+            .add_systems(Simulation, rotate_offers)
+            .add_systems(Update, on_trade_place);
+    }
+}
+
+// constants for the animal market
+/// Number of animals offered on the market at once
+const OFFER_COUNT: usize = 4;
+/// Fraction of an animal's value that the zoo is paid when selling it
+const SALE_FRACTION: f32 = 0.6;
+/// Reputation gained for releasing an animal into the wild worth as much as its species' base cost
+const RELEASE_REPUTATION: f32 = 3.0;
+
+/// Worth of an animal of the given age and traits, peaking once it becomes an adult
+pub fn animal_value(data: &AnimalData, age: f32, traits: &AnimalTraits) -> Currency {
+    let life = &data.life;
+
+    // young animals are worth less until grown, and old animals less the closer they are to their lifespan
+    let age_factor = match age < life.maturity {
+        true => 0.6 + 0.4 * age / life.maturity,
+        false => {
+            let aging = (age - life.maturity) / (life.lifespan - life.maturity);
+            1.0 - 0.7 * aging.clamp(0.0, 1.0)
+        }
+    };
+
+    let trait_factor = (traits.size + traits.fertility + traits.hardiness + traits.appeal) / 4.0;
+
+    data.cost * age_factor * trait_factor
+}
+
+/// Animal available to buy from the market
+#[derive(Clone)]
+pub struct MarketOffer {
+    /// Unique identifier so the offer can be found once it has been bought
+    pub id: u64,
+    pub species: Handle<AnimalData>,
+    pub species_name: String,
+    pub icon: Handle<Image>,
+
+    pub age: f32,
+    pub sex: Sex,
+    pub traits: AnimalTraits,
+    pub price: Currency,
+}
+
+impl MarketOffer {
+    /// Life of the animal once bought, matching what was offered
+    pub(super) fn life(&self, rng: &mut SeededRng) -> AnimalLife {
+        AnimalLife::new(self.age, self.sex, self.traits, rng)
+    }
+}
+
+impl PreviewData for MarketOffer {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        spawn_animal_preview(commands, self.species.clone(), Some(self));
+    }
+}
+
+impl UiDisplay for MarketOffer {
+    fn name(&self) -> String {
+        let sex = match self.sex {
+            Sex::Male => "M",
+            Sex::Female => "F",
+        };
+
+        format!("{} ({}, {:.0} yrs)", self.species_name, sex, self.age)
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        self.price.comma_separated()
+    }
+}
+
+/// Rotating selection of individual animals that the zoo can buy
+#[derive(Resource)]
+pub struct AnimalMarket {
+    offers: Vec<MarketOffer>,
+    next_id: u64,
+    rng: SeededRng,
+}
+
+impl FromWorld for AnimalMarket {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            offers: Vec::new(),
+            next_id: 0,
+            rng: world.resource_mut::<SeededRng>().fork(),
+        }
+    }
+}
+
+impl AnimalMarket {
+    /// Returns every animal currently being offered
+    pub fn offers(&self) -> &[MarketOffer] {
+        &self.offers
+    }
+
+    /// Removes the offer with the given id from the market, returning it if it was still available
+    pub fn take(&mut self, id: u64) -> Option<MarketOffer> {
+        let index = self.offers.iter().position(|offer| offer.id == id)?;
+        Some(self.offers.remove(index))
+    }
+
+    /// Replaces all offers with new animals from the given species
+    fn rotate(&mut self, species: &[(Handle<AnimalData>, &AnimalData)]) {
+        self.offers.clear();
+
+        for _ in 0..OFFER_COUNT {
+            let Some((handle, data)) = self.rng.pick(species) else { return };
+
+            let age = self.rng.range(0.5..data.life.lifespan * 0.6);
+            let sex = AnimalLife::random_sex(&mut self.rng);
+            let traits = AnimalTraits::random(&mut self.rng);
+
+            self.offers.push(MarketOffer {
+                id: self.next_id,
+                species: handle.clone(),
+                species_name: data.species.clone(),
+                icon: data.icon.clone(),
+                age,
+                sex,
+                traits,
+                price: animal_value(data, age, &traits),
+            });
+
+            self.next_id += 1;
+        }
+    }
+}
+
+/// Component marking an animal preview that was bought from the market
+#[derive(Component)]
+pub(super) struct MarketPurchase {
+    pub offer: u64,
+}
+
+/// Replaces the market's offers at the start of every month, first stocking it as soon as the zoo opens
+fn rotate_offers(
+    clock: Res<ZooClock>,
+    animal_data: Res<Assets<AnimalData>>,
+    mut market: ResMut<AnimalMarket>,
+    mut stocked: Local<bool>,
+) {
+    if *stocked && !clock.is_new_month() {
+        return;
+    }
+    *stocked = true;
+
+    // sort species so that offers don't depend on the order assets are stored in
+    let mut species = animal_data
+        .iter()
+        .map(|(handle_id, data)| (animal_data.get_handle(handle_id), data))
+        .collect::<Vec<_>>();
+    species.sort_by(|(_, a), (_, b)| a.species.cmp(&b.species));
+
+    market.rotate(&species);
+}
+
+/// Ways that the zoo can part with an animal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TradeAction {
+    /// Sells the animal back to the market for part of its value
+    Sell,
+    /// Releases the animal into the wild for reputation
+    Release,
+}
+
+/// Tool that can be selected from the ui to sell or release the clicked animal
+#[derive(Clone)]
+pub struct AnimalTradeTool {
+    pub action: TradeAction,
+    pub icon: Handle<Image>,
+}
+
+impl UiDisplay for AnimalTradeTool {
+    fn name(&self) -> String {
+        match self.action {
+            TradeAction::Sell => "Sell Animal".into(),
+            TradeAction::Release => "Release to Wild".into(),
+        }
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        match self.action {
+            TradeAction::Sell => format!("{:.0}% of value", SALE_FRACTION * 100.0),
+            TradeAction::Release => "Earns reputation".into(),
+        }
+    }
+}
+
+impl PreviewData for AnimalTradeTool {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpatialBundle::default(),
+            TradePreview {
+                action: self.action,
+            },
+            Preview { cost: 0.0 },
+        ));
+    }
+}
+
+/// Collection of all animal trading tools
+#[derive(Resource)]
+pub struct AnimalTradeTools {
+    pub tools: Vec<AnimalTradeTool>,
+}
+
+impl FromWorld for AnimalTradeTools {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        Self {
+            tools: vec![
+                AnimalTradeTool {
+                    action: TradeAction::Sell,
+                    icon: asset_server.load("test.png"),
+                },
+                AnimalTradeTool {
+                    action: TradeAction::Release,
+                    icon: asset_server.load("test.png"),
+                },
+            ],
+        }
+    }
+}
+
+/// Preview for the currently selected trading tool
+#[derive(Component)]
+struct TradePreview {
+    action: TradeAction,
+}
+
+/// Records and events that are updated whenever the zoo parts with an animal
+#[derive(SystemParam)]
+struct TradeOutcomes<'w> {
+    records: ResMut<'w, PopulationRecords>,
+    balance_changes: EventWriter<'w, ZooBalanceChange>,
+    reputation_changes: EventWriter<'w, ZooReputationChange>,
+    log_messages: EventWriter<'w, ZooLogMessage>,
+}
+
+/// Sells or releases the animal under the cursor when placed
+fn on_trade_place(
+    mut commands: Commands,
+    cursor: CursorRaycast,
+    animal_data: Res<Assets<AnimalData>>,
+    trade_preview: Query<&TradePreview>,
+    animals: Query<(&Animal, &AnimalLife), Without<Preview>>,
+
+    mut placements: EventReader<PlacePreview>,
+    mut outcomes: TradeOutcomes,
+) {
+    for _ in placements.iter() {
+        let Ok(trade_preview) = trade_preview.get_single() else { continue };
+        let Some((entity, _)) = cursor.raycast(CollisionLayer::Object.filter()) else { continue };
+        let Ok((animal, life)) = animals.get(entity) else { continue };
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        let value = animal_value(data, life.age, &life.traits);
+
+        match trade_preview.action {
+            TradeAction::Sell => {
+                let price = value * SALE_FRACTION;
+
+                outcomes.records.sold += 1;
+                outcomes.balance_changes.send(ZooBalanceChange {
+                    amount: price,
+                    category: LedgerCategory::Animals,
+                });
+                outcomes.log_messages.send(ZooLogMessage {
+                    message: format!("Sold a {} for {}", data.species, price.comma_separated()),
+                });
+            }
+
+            TradeAction::Release => {
+                outcomes.records.released += 1;
+                outcomes.reputation_changes.send(ZooReputationChange {
+                    amount: RELEASE_REPUTATION * value / data.cost,
+                });
+                outcomes.log_messages.send(ZooLogMessage {
+                    message: format!("Released a {} into the wild", data.species),
+                });
+            }
+        }
+
+        commands.entity(entity).despawn_recursive();
+    }
+}
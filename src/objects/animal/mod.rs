@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+mod behavior;
+mod components;
+mod data;
+mod escape;
+mod habitat;
+mod health;
+mod lifecycle;
+mod market;
+mod needs;
+mod placement;
+mod waste;
+
+pub use behavior::AnimalBehavior;
+pub use components::Animal;
+pub use data::{AnimalData, Food};
+pub use escape::{Escaped, RecaptureAnimal};
+pub use health::{AnimalHealth, TreatAnimal};
+pub use lifecycle::PopulationRecords;
+pub use market::{AnimalMarket, AnimalTradeTools};
+pub use needs::{AnimalNeeds, AnimalWelfare};
+
+pub struct AnimalPlugin;
+impl Plugin for AnimalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            behavior::AnimalBehaviorPlugin,
+            data::AnimalDataPlugin,
+            escape::AnimalEscapePlugin,
+            habitat::AnimalHabitatPlugin,
+            health::AnimalHealthPlugin,
+            lifecycle::AnimalLifecyclePlugin,
+            market::AnimalMarketPlugin,
+            needs::AnimalNeedsPlugin,
+            placement::AnimalPlacementPlugin,
+            waste::AnimalWastePlugin,
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+use super::{
+    behavior::{Activity, AnimalBehavior},
+    components::Animal,
+    habitat::EnclosureHabitat,
+    AnimalData,
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::{Enclosure, FoodSupply, Prop, PropData, PropFeature},
+    placement::Preview,
+    terrain::Terrain,
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+use std::collections::HashMap;
+
+pub struct AnimalNeedsPlugin;
+impl Plugin for AnimalNeedsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AnimalWelfare>().add_systems(
+            Simulation,
+            (update_needs, seek_needs, update_welfare).chain(),
+        );
+    }
+}
+
+// constants for animal needs
+/// Fraction of the hunger meter lost each second
+const HUNGER_DECAY: f32 = 1.0 / 240.0;
+/// Fraction of the thirst meter lost each second
+const THIRST_DECAY: f32 = 1.0 / 180.0;
+/// Fraction of the rest meter lost each second while awake
+const REST_DECAY: f32 = 1.0 / 300.0;
+/// Fraction of a need restored each second while eating, drinking or resting in a shelter
+const SATISFY_RATE: f32 = 0.2;
+/// Fraction of a food prop's supply eaten each second by a single animal
+const FOOD_CONSUMPTION: f32 = 1.0 / 40.0;
+/// Fraction of the rest meter restored each second while resting out in the open
+const REST_RATE: f32 = 0.05;
+/// Fraction of the remaining difference that social, space and habitat needs close each second
+const ADJUST_RATE: f32 = 0.05;
+/// Animals go looking for whatever satisfies a need once it drops below this
+const SEEK_THRESHOLD: f32 = 0.4;
+
+/// Meters for each of an animal's needs, ranging from 0 when desperate to 1 when fully satisfied
+#[derive(Component, Clone, Debug)]
+pub struct AnimalNeeds {
+    pub hunger: f32,
+    pub thirst: f32,
+    pub rest: f32,
+    /// How comfortable the animal is with the size of its group
+    pub social: f32,
+    /// How comfortable the animal is with the space it has in its enclosure
+    pub space: f32,
+    /// How well the animal's enclosure suits its species
+    pub habitat: f32,
+}
+
+impl Default for AnimalNeeds {
+    fn default() -> Self {
+        Self {
+            hunger: 1.0,
+            thirst: 1.0,
+            rest: 1.0,
+            social: 1.0,
+            space: 1.0,
+            habitat: 1.0,
+        }
+    }
+}
+
+impl AnimalNeeds {
+    /// Moves the social, space and habitat needs towards what the animal's surroundings provide, keeping every need in range
+    fn adjust(&mut self, social: f32, space: f32, habitat: f32, delta: f32) {
+        let blend = (ADJUST_RATE * delta).min(1.0);
+        self.social += (social - self.social) * blend;
+        self.space += (space - self.space) * blend;
+        self.habitat += (habitat - self.habitat) * blend;
+
+        self.hunger = self.hunger.clamp(0.0, 1.0);
+        self.thirst = self.thirst.clamp(0.0, 1.0);
+        self.rest = self.rest.clamp(0.0, 1.0);
+    }
+
+    /// Overall wellbeing of the animal from 0 to 1, weighing each of its needs
+    pub fn welfare(&self) -> f32 {
+        self.hunger * 0.2
+            + self.thirst * 0.2
+            + self.rest * 0.1
+            + self.social * 0.15
+            + self.space * 0.15
+            + self.habitat * 0.2
+    }
+}
+
+/// Summary of the welfare of every animal in the zoo
+#[derive(Resource, Default)]
+pub struct AnimalWelfare {
+    /// Average welfare of all animals from 0 to 1, which is 0 when there are no animals
+    pub average: f32,
+    pub animals: usize,
+}
+
+/// Decays needs over time and satisfies them based on what the animal is doing and who it lives with
+fn update_needs(
+    clock: Res<ZooClock>,
+    animal_data: Res<Assets<AnimalData>>,
+    prop_data: Res<Assets<PropData>>,
+    enclosures: Query<(&Enclosure, Option<&EnclosureHabitat>)>,
+    mut props: Query<(&Prop, Option<&mut FoodSupply>), Without<Preview>>,
+    mut animals: Query<(&Animal, Option<&AnimalBehavior>, &mut AnimalNeeds), Without<Preview>>,
+) {
+    let delta = clock.delta_seconds();
+
+    // count residents of each enclosure, both in total and per species
+    let mut residents = HashMap::<Entity, u32>::new();
+    let mut species_residents = HashMap::<(Entity, Handle<AnimalData>), u32>::new();
+    for (animal, behavior, _) in animals.iter() {
+        let Some(behavior) = behavior else { continue };
+
+        *residents.entry(behavior.enclosure).or_default() += 1;
+        *species_residents
+            .entry((behavior.enclosure, animal.data.clone()))
+            .or_default() += 1;
+    }
+
+    for (animal, behavior, mut needs) in animals.iter_mut() {
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+
+        needs.hunger -= HUNGER_DECAY * delta;
+        needs.thirst -= THIRST_DECAY * delta;
+
+        // escaped animals have nothing provided for them and are cut off from their group, though have all the space they want
+        let Some(behavior) = behavior else {
+            needs.rest -= REST_DECAY * delta;
+            needs.adjust(0.0, 1.0, 0.0, delta);
+            continue;
+        };
+        let Ok((enclosure, habitat)) = enclosures.get(behavior.enclosure) else { continue };
+
+        match behavior.activity {
+            Activity::Rest { .. } => needs.rest += REST_RATE * delta,
+            _ => needs.rest -= REST_DECAY * delta,
+        }
+
+        // lingering at a point of interest satisfies whatever need it provides for
+        if let Activity::Linger { target, .. } = behavior.activity {
+            // lingering at the enclosure itself means the animal is drinking from its water
+            let feature = match target == behavior.enclosure {
+                true => Some(PropFeature::Water),
+                false => props
+                    .get(target)
+                    .ok()
+                    .and_then(|(prop, _)| prop_data.get(&prop.data))
+                    .map(|prop_data| prop_data.feature),
+            };
+
+            match feature {
+                Some(PropFeature::Food(food)) if data.diet.eats(food) => {
+                    // food only satisfies hunger while there is some left to eat
+                    if let Ok((_, Some(mut supply))) = props.get_mut(target) {
+                        if supply.remaining > 0.0 {
+                            supply.remaining =
+                                (supply.remaining - FOOD_CONSUMPTION * delta).max(0.0);
+                            needs.hunger += SATISFY_RATE * delta;
+                        }
+                    }
+                }
+                Some(PropFeature::Water) => needs.thirst += SATISFY_RATE * delta,
+                Some(PropFeature::Shelter) => needs.rest += SATISFY_RATE * delta,
+                _ => (),
+            }
+        }
+
+        // social needs are met by living in a group within the species' preferred size
+        let group = species_residents[&(behavior.enclosure, animal.data.clone())] as f32;
+        let social = match (data.social.min_group as f32, data.social.max_group as f32) {
+            (min, _) if group < min => group / min,
+            (_, max) if group > max => max / group,
+            _ => 1.0,
+        };
+
+        // space needs are met when the enclosure is shared by few enough animals
+        let area_per_animal = enclosure.polygon.area() / residents[&behavior.enclosure] as f32;
+        let space = (area_per_animal / data.habitat.area_per_animal).min(1.0);
+
+        // habitat needs are met by an enclosure that suits the species, once it has been surveyed
+        let habitat = habitat
+            .and_then(|habitat| habitat.report(&animal.data))
+            .map_or(needs.habitat, |report| report.score);
+
+        needs.adjust(social, space, habitat, delta);
+    }
+}
+
+/// Placed props that animals can seek out to satisfy their needs
+type NeedProps<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Prop,
+        &'static Transform,
+        Option<&'static FoodSupply>,
+    ),
+    (Without<Preview>, Without<Animal>),
+>;
+
+/// Sends animals off to eat, drink or rest when their needs become low
+fn seek_needs(
+    terrain: Res<Terrain>,
+    animal_data: Res<Assets<AnimalData>>,
+    prop_data: Res<Assets<PropData>>,
+    enclosures: Query<&Enclosure>,
+    props: NeedProps,
+    mut animals: Query<(&Animal, &mut AnimalBehavior, &AnimalNeeds, &Transform), Without<Preview>>,
+) {
+    for (animal, mut behavior, needs, transform) in animals.iter_mut() {
+        // only interrupt animals that are idling or wandering around
+        if !matches!(
+            behavior.activity,
+            Activity::Idle { .. } | Activity::Roam { .. }
+        ) {
+            continue;
+        }
+
+        let Some(data) = animal_data.get(&animal.data) else { continue };
+        let Ok(enclosure) = enclosures.get(behavior.enclosure) else {
+            continue;
+        };
+
+        let position = transform.translation.xz();
+        let margin = data.size / 2.0;
+
+        // finds the closest prop within the enclosure that satisfies the filter
+        let nearest_prop = |filter: &dyn Fn(PropFeature) -> bool| {
+            props
+                .iter()
+                .filter(|(_, prop, _, supply)| {
+                    // empty food props aren't worth visiting until they are refilled
+                    !supply.is_some_and(|supply| supply.remaining <= 0.0)
+                        && prop_data
+                            .get(&prop.data)
+                            .is_some_and(|prop_data| filter(prop_data.feature))
+                })
+                .map(|(entity, _, transform, _)| (entity, transform.translation.xz()))
+                .filter(|&(_, point)| enclosure.contains(point))
+                .min_by(|(_, a), (_, b)| {
+                    a.distance_squared(position)
+                        .total_cmp(&b.distance_squared(position))
+                })
+        };
+
+        if needs.hunger < SEEK_THRESHOLD {
+            let food = nearest_prop(
+                &|feature| matches!(feature, PropFeature::Food(food) if data.diet.eats(food)),
+            );
+
+            if let Some((target, point)) = food {
+                behavior.activity = AnimalBehavior::visit(position, margin, target, point);
+                continue;
+            }
+        }
+
+        if needs.thirst < SEEK_THRESHOLD {
+            if let Some((target, point)) = nearest_prop(&|feature| feature == PropFeature::Water) {
+                behavior.activity = AnimalBehavior::visit(position, margin, target, point);
+                continue;
+            }
+
+            // otherwise drink from any water within the enclosure that the animal can stand in
+            let shore = behavior.random_point(enclosure, margin, |point| {
+                terrain.water_depth_at(point) > 0.0
+                    && terrain.is_passable(point, data.habitat.mobility)
+            });
+
+            if let Some(destination) = shore {
+                behavior.activity = Activity::Visit {
+                    destination,
+                    target: behavior.enclosure,
+                };
+                continue;
+            }
+        }
+
+        if needs.rest < SEEK_THRESHOLD {
+            behavior.activity = match nearest_prop(&|feature| feature == PropFeature::Shelter) {
+                Some((target, point)) => AnimalBehavior::visit(position, margin, target, point),
+                None => behavior.rest(),
+            };
+        }
+    }
+}
+
+/// Keeps the zoo-wide welfare summary up to date
+fn update_welfare(
+    mut welfare: ResMut<AnimalWelfare>,
+    animals: Query<&AnimalNeeds, Without<Preview>>,
+) {
+    let animals = animals.iter().map(AnimalNeeds::welfare).collect::<Vec<_>>();
+
+    welfare.animals = animals.len();
+    welfare.average = match animals.is_empty() {
+        true => 0.0,
+        false => animals.iter().sum::<f32>() / animals.len() as f32,
+    };
+}
@@ -0,0 +1,180 @@
+use super::{
+    behavior::AnimalBehavior,
+    components::Animal,
+    health::AnimalHealth,
+    lifecycle::AnimalLife,
+    market::{AnimalMarket, MarketOffer, MarketPurchase},
+    needs::AnimalNeeds,
+};
+use crate::{
+    camera::CursorRaycast,
+    finance::LedgerCategory,
+    objects::{
+        utility::{ColliderMesh, CollisionLayer, RenderGltf, RenderGltfMode},
+        AnimalData, Enclosure, ObjectBundle,
+    },
+    placement::{ClearPreview, PlacePreview, Preview, PreviewData},
+    rating::ZooRating,
+    rng::SeededRng,
+    terrain::SeatOnTerrain,
+    zoo::{Zoo, ZooBalanceChange, ZooLogMessage},
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+use bevy_rapier3d::prelude::*;
+
+pub struct AnimalPlacementPlugin;
+impl Plugin for AnimalPlacementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (handle_preview_movement, on_preview_place));
+    }
+}
+
+impl PreviewData for Handle<AnimalData> {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        spawn_animal_preview(commands, self.clone(), None);
+    }
+}
+
+/// Spawns a preview of the given animal, optionally bought from a market offer instead of at the base cost
+pub(super) fn spawn_animal_preview(
+    commands: &mut Commands,
+    animal_handle: Handle<AnimalData>,
+    offer: Option<&MarketOffer>,
+) {
+    let purchase = offer.map(|offer| (MarketPurchase { offer: offer.id }, offer.price));
+
+    commands.add(move |world: &mut World| {
+        // get the animal data from assets collection
+        let animals = world.resource::<Assets<AnimalData>>();
+        let animal_data = animals.get(&animal_handle).unwrap();
+
+        let cost = match purchase {
+            Some((_, price)) => price,
+            None => animal_data.cost,
+        };
+
+        // spawn animal preview
+        let mut preview = world.spawn((
+            ObjectBundle {
+                object: Animal {
+                    data: animal_handle,
+                },
+                spatial: SpatialBundle::default(),
+                gltf: RenderGltf {
+                    handle: animal_data.model.clone(),
+                    mode: RenderGltfMode::Preview,
+                },
+                collider: ColliderMesh {
+                    mesh: animal_data.collider.clone(),
+                    rb: RigidBody::KinematicPositionBased,
+                    membership: CollisionLayer::None,
+                },
+            },
+            Preview { cost },
+        ));
+
+        if let Some((market_purchase, _)) = purchase {
+            preview.insert(market_purchase);
+        }
+    });
+}
+
+/// Animal preview that follows the cursor, hidden while it isn't over the ground
+type AnimalPreview<'w, 's> =
+    Query<'w, 's, (&'static mut Transform, &'static mut Visibility), (With<Animal>, With<Preview>)>;
+
+fn handle_preview_movement(cursor: CursorRaycast, mut preview: AnimalPreview) {
+    if let Ok((mut transform, mut visibility)) = preview.get_single_mut() {
+        match cursor.ground_point() {
+            Some(position) => {
+                transform.translation = position;
+                *visibility = Visibility::Visible;
+            }
+
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// Resources deciding whether an animal can be bought, and what it is like once it has been
+#[derive(SystemParam)]
+struct AnimalPurchasing<'w> {
+    animal_data: Res<'w, Assets<AnimalData>>,
+    zoo: Res<'w, Zoo>,
+    rating: Res<'w, ZooRating>,
+    rng: ResMut<'w, SeededRng>,
+    market: ResMut<'w, AnimalMarket>,
+}
+
+/// Events sent once an animal has been bought
+#[derive(SystemParam)]
+struct PurchaseEvents<'w> {
+    balance_changes: EventWriter<'w, ZooBalanceChange>,
+    clears: EventWriter<'w, ClearPreview>,
+    log_messages: EventWriter<'w, ZooLogMessage>,
+}
+
+fn on_preview_place(
+    mut commands: Commands,
+    mut purchasing: AnimalPurchasing,
+    enclosures: Query<(Entity, &Enclosure)>,
+    preview: Query<(&Animal, &Transform, &Preview, Option<&MarketPurchase>)>,
+
+    mut placements: EventReader<PlacePreview>,
+    mut events: PurchaseEvents,
+) {
+    for _ in placements.iter() {
+        let Ok((preview, transform, cost, purchase)) = preview.get_single() else { continue };
+        let Some(animal_data) = purchasing.animal_data.get(&preview.data) else { continue };
+
+        // animals can only be placed inside of an enclosure
+        let position = transform.translation.xz();
+        let Some((enclosure, _)) = enclosures
+            .iter()
+            .find(|(_, enclosure)| enclosure.contains(position))
+        else {
+            continue;
+        };
+
+        // rarer species are only unlocked once the zoo is rated highly enough
+        if purchasing.zoo.balance() < cost.cost || !purchasing.rating.meets(animal_data.rating) {
+            continue;
+        }
+
+        // animals bought from the market keep the age, sex and traits they were offered with
+        let mut animal_rng = purchasing.rng.fork();
+        let life = match purchase {
+            Some(purchase) => {
+                // the offer may have been rotated off the market while its preview was held
+                let Some(offer) = purchasing.market.take(purchase.offer) else {
+                    events.clears.send(ClearPreview);
+                    events.log_messages.send(ZooLogMessage {
+                        message: format!("The {} offer has expired", animal_data.species),
+                    });
+                    continue;
+                };
+                offer.life(&mut animal_rng)
+            }
+            None => AnimalLife::bought(animal_data, &mut animal_rng),
+        };
+
+        commands.spawn((
+            Animal::bundle(&preview.data, animal_data, *transform),
+            AnimalBehavior::new(enclosure, animal_rng),
+            AnimalNeeds::default(),
+            AnimalHealth::default(),
+            life,
+            SeatOnTerrain,
+        ));
+
+        events.balance_changes.send(ZooBalanceChange {
+            amount: -cost.cost,
+            category: LedgerCategory::Animals,
+        });
+
+        // the offer has been sold, so another of it can't be placed
+        if purchase.is_some() {
+            events.clears.send(ClearPreview);
+        }
+    }
+}
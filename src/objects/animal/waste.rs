@@ -0,0 +1,44 @@
+use super::behavior::AnimalBehavior;
+use crate::{
+    clock::{Simulation, ZooClock},
+    litter::{LitterBundle, LitterKind},
+    placement::Preview,
+};
+use bevy::prelude::*;
+
+pub struct AnimalWastePlugin;
+impl Plugin for AnimalWastePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, produce_waste);
+    }
+}
+
+// constants for animal waste
+/// Seconds between each check for animals leaving waste behind
+const WASTE_INTERVAL: f32 = 5.0;
+/// Chance at each check that an animal leaves waste where it stands
+const WASTE_CHANCE: f32 = 0.02;
+
+/// Periodically lets animals leave waste within their enclosure, which keepers have to clean up
+fn produce_waste(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    mut animals: Query<(&mut AnimalBehavior, &Transform), Without<Preview>>,
+    mut since_check: Local<f32>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < WASTE_INTERVAL {
+        return;
+    }
+    *since_check = 0.0;
+
+    for (mut behavior, transform) in animals.iter_mut() {
+        if behavior.rng.chance(WASTE_CHANCE) {
+            commands.spawn(LitterBundle::new(
+                LitterKind::Waste,
+                Some(behavior.enclosure),
+                transform.translation,
+            ));
+        }
+    }
+}
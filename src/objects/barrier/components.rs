@@ -1,16 +1,30 @@
-use super::{
-    cycle::{BarrierCycleUtil, UndirectedGraph},
-    BarrierData,
+use super::BarrierData;
+use crate::{
+    clock::{Simulation, ZooClock},
+    objects::{
+        utility::{ColliderMesh, CollisionLayer, GroundRaycast, RenderGltf, RenderGltfMode},
+        ObjectBundle,
+    },
+    placement::Preview,
+    terrain::SeatOnTerrain,
 };
-use bevy::prelude::*;
+use bevy::{math::Vec3Swizzles, prelude::*};
+use bevy_rapier3d::prelude::*;
 
 pub struct BarrierComponentPlugin;
 impl Plugin for BarrierComponentPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<PossibleBarrierCycle>()
-            .add_systems(Update, (mark_fence_movement, handle_fence_movement))
-            // on_possible_cycle needs to ensure that the placed barriers are all actually spawned in when it runs
-            .add_systems(PostUpdate, on_possible_cycle);
+            .add_systems(
+                Update,
+                (
+                    mark_fence_movement,
+                    subdivide_uneven_fences,
+                    handle_fence_movement,
+                )
+                    .chain(),
+            )
+            .add_systems(Simulation, wear_fences);
     }
 }
 
@@ -30,6 +44,64 @@ pub struct BarrierFence {
     pub connection: [Entity; 2],
 }
 
+/// How well maintained a placed fence is, kept apart from `BarrierFence` so that wear doesn't count as movement
+#[derive(Component)]
+pub struct FenceCondition {
+    /// From 0 when falling apart to 1 when like new
+    pub condition: f32,
+}
+
+impl BarrierPost {
+    /// Object bundle for a permanently placed post with no connecting fences
+    pub(super) fn bundle(
+        data_handle: &Handle<BarrierData>,
+        data: &BarrierData,
+    ) -> ObjectBundle<BarrierPost> {
+        ObjectBundle {
+            object: BarrierPost {
+                data: data_handle.clone(),
+                fences: vec![],
+            },
+            spatial: SpatialBundle::default(),
+            gltf: RenderGltf {
+                handle: data.post_model.clone(),
+                mode: RenderGltfMode::Regular,
+            },
+            collider: ColliderMesh {
+                mesh: data.post_collider.clone(),
+                rb: RigidBody::Fixed,
+                membership: CollisionLayer::Object,
+            },
+        }
+    }
+}
+
+impl BarrierFence {
+    /// Object bundle for a permanently placed fence between the given posts
+    pub(super) fn bundle(
+        data_handle: &Handle<BarrierData>,
+        data: &BarrierData,
+        connection: [Entity; 2],
+    ) -> ObjectBundle<BarrierFence> {
+        ObjectBundle {
+            object: BarrierFence {
+                data: data_handle.clone(),
+                connection,
+            },
+            spatial: SpatialBundle::default(),
+            gltf: RenderGltf {
+                handle: data.fence_model.clone(),
+                mode: RenderGltfMode::Regular,
+            },
+            collider: ColliderMesh {
+                mesh: data.fence_collider.clone(),
+                rb: RigidBody::Fixed,
+                membership: CollisionLayer::Object,
+            },
+        }
+    }
+}
+
 /// Event to be called whenever a possible cycle was created between barrier posts
 #[derive(Event)]
 pub struct PossibleBarrierCycle {
@@ -50,7 +122,140 @@ fn mark_fence_movement(
     }
 }
 
+// constants for fitting fences to uneven ground
+/// Largest vertical gap allowed between a fence and the ground beneath it before it is subdivided
+const MAX_FENCE_GAP: f32 = 0.1;
+/// Shortest fence that subdivision is allowed to produce
+const MIN_FENCE_LENGTH: f32 = 0.5;
+/// Horizontal spacing between ground samples taken along a fence
+const FENCE_SAMPLE_SPACING: f32 = 0.25;
+
+/// Permanent fences that have been placed or had their connection changed
+type ChangedFences<'w, 's> =
+    Query<'w, 's, (Entity, &'static mut BarrierFence), (Changed<BarrierFence>, Without<Preview>)>;
+
+/// Splits permanent fences with additional posts wherever the ground beneath them is too uneven
+fn subdivide_uneven_fences(
+    mut commands: Commands,
+    barriers: Res<Assets<BarrierData>>,
+    ground: GroundRaycast,
+
+    mut changed_fences: ChangedFences,
+    mut posts: Query<(&mut BarrierPost, &Transform)>,
+) {
+    for (fence_entity, mut fence) in changed_fences.iter_mut() {
+        // get positions of both posts
+        let Ok(from) = posts.get(fence.connection[0]).map(|(_, t)| t.translation) else { continue };
+        let Ok(to) = posts.get(fence.connection[1]).map(|(_, t)| t.translation) else { continue };
+
+        let subdivisions = fence_subdivisions(from, to, |point| ground.ground_below(point));
+        if subdivisions.is_empty() {
+            continue;
+        }
+
+        let Some(barrier_data) = barriers.get(&fence.data) else { continue };
+
+        // this fence is kept as the first segment, one new post and fence are spawned per subdivision
+        let end_post = fence.connection[1];
+        let new_posts = subdivisions
+            .iter()
+            .map(|_| commands.spawn_empty().id())
+            .collect::<Vec<_>>();
+        let new_fences = subdivisions
+            .iter()
+            .map(|_| commands.spawn_empty().id())
+            .collect::<Vec<_>>();
+
+        for (i, &position) in subdivisions.iter().enumerate() {
+            let previous_fence = match i {
+                0 => fence_entity,
+                _ => new_fences[i - 1],
+            };
+            let next_post = new_posts.get(i + 1).copied().unwrap_or(end_post);
+
+            let mut post_bundle = BarrierPost::bundle(&fence.data, barrier_data);
+            post_bundle.object.fences = vec![previous_fence, new_fences[i]];
+            post_bundle.spatial.transform = Transform::from_translation(position);
+            commands
+                .entity(new_posts[i])
+                .insert((post_bundle, SeatOnTerrain));
+
+            let fence_bundle =
+                BarrierFence::bundle(&fence.data, barrier_data, [new_posts[i], next_post]);
+            commands.entity(new_fences[i]).insert(fence_bundle);
+        }
+
+        // end post is now connected to the last new fence instead of this one
+        if let Ok((mut end, _)) = posts.get_mut(end_post) {
+            for connected_fence in end.fences.iter_mut() {
+                if *connected_fence == fence_entity {
+                    *connected_fence = *new_fences.last().unwrap();
+                }
+            }
+        }
+
+        fence.connection[1] = new_posts[0];
+    }
+}
+
+/// Finds the points along a fence's span where additional posts are required for it to follow the ground
+///
+/// Applies the Ramer-Douglas-Peucker algorithm to the ground profile sampled beneath the span
+fn fence_subdivisions(
+    from: Vec3,
+    to: Vec3,
+    ground_below: impl Fn(Vec3) -> Option<Vec3>,
+) -> Vec<Vec3> {
+    let samples = (from.xz().distance(to.xz()) / FENCE_SAMPLE_SPACING) as usize;
+
+    // ground profile between the posts, including both posts as the endpoints
+    let profile = std::iter::once(from)
+        .chain((1..samples).filter_map(|i| ground_below(from.lerp(to, i as f32 / samples as f32))))
+        .chain(std::iter::once(to))
+        .collect::<Vec<_>>();
+
+    let mut subdivisions = Vec::new();
+    subdivide_profile(&profile, &mut subdivisions);
+
+    subdivisions
+}
+
+/// Recursively splits the ground profile at the sample furthest from a straight fence, in order along the profile
+fn subdivide_profile(profile: &[Vec3], subdivisions: &mut Vec<Vec3>) {
+    let (first, last) = (profile[0], profile[profile.len() - 1]);
+    let length = first.xz().distance(last.xz());
+
+    // find the inner sample with the largest vertical gap to a straight fence between the endpoints
+    let furthest = profile
+        .iter()
+        .enumerate()
+        .filter(|(_, sample)| {
+            // only consider splits that leave both fences long enough
+            let distance = first.xz().distance(sample.xz());
+            distance >= MIN_FENCE_LENGTH && length - distance >= MIN_FENCE_LENGTH
+        })
+        .map(|(i, sample)| {
+            let t = first.xz().distance(sample.xz()) / length;
+            let fence_height = first.y + (last.y - first.y) * t;
+            (i, (sample.y - fence_height).abs())
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    match furthest {
+        Some((i, gap)) if gap > MAX_FENCE_GAP => {
+            subdivide_profile(&profile[..=i], subdivisions);
+            subdivisions.push(profile[i]);
+            subdivide_profile(&profile[i..], subdivisions);
+        }
+
+        // fence is close enough to the ground along its entire length
+        _ => (),
+    }
+}
+
 /// Handles adjusting the fence's transform if its `BarrierFence` component has been changed
+///
+/// Fences are rotated to face between both posts and pitched to follow any height difference between them
 fn handle_fence_movement(
     mut moved_fences: Query<(&BarrierFence, &mut Transform), Changed<BarrierFence>>,
     all_posts: Query<&Transform, (With<BarrierPost>, Without<BarrierFence>)>,
@@ -61,29 +266,106 @@ fn handle_fence_movement(
         let Ok(to) = all_posts.get(fence.connection[1]).map(|t| t.translation) else { continue };
 
         // and calculate transform values for this fence
+        let delta = to - from;
         let midpoint = from.lerp(to, 0.5);
-        let angle = -f32::atan2(to.z - from.z, to.x - from.x);
-        let distance = from.distance(to);
+        let yaw = -f32::atan2(delta.z, delta.x);
+        let pitch = f32::atan2(delta.y, delta.xz().length());
+        let distance = delta.length();
 
         *transform = Transform {
             translation: midpoint,
-            rotation: Quat::from_rotation_y(angle),
+            rotation: Quat::from_rotation_y(yaw) * Quat::from_rotation_z(pitch),
             scale: Vec3::new(distance, 1.0, 1.0),
         };
     }
 }
 
-fn on_possible_cycle(
-    mut possible_cycles: EventReader<PossibleBarrierCycle>,
-    cycle_util: BarrierCycleUtil,
+/// Fraction of a fence's condition lost each second to weather and animals
+const FENCE_WEAR: f32 = 1.0 / 1200.0;
+
+/// Placed fences that haven't been given a condition yet
+type NewFences<'w, 's> = Query<
+    'w,
+    's,
+    Entity,
+    (
+        With<BarrierFence>,
+        Without<FenceCondition>,
+        Without<Preview>,
+    ),
+>;
+
+/// Slowly wears down every placed fence, giving new fences their starting condition
+fn wear_fences(
+    mut commands: Commands,
+    clock: Res<ZooClock>,
+    new_fences: NewFences,
+    mut fences: Query<&mut FenceCondition>,
 ) {
-    for possible_cycle in possible_cycles.iter() {
-        match cycle_util.get_cycle(possible_cycle.root) {
-            Some(list) => {
-                println!("Cycle found! {} barriers in cycle:", list.len());
-                println!("{:?}", list);
-            }
-            None => println!("Cycle not found"),
+    for entity in new_fences.iter() {
+        commands
+            .entity(entity)
+            .insert(FenceCondition { condition: 1.0 });
+    }
+
+    for mut fence in fences.iter_mut() {
+        fence.condition = (fence.condition - FENCE_WEAR * clock.delta_seconds()).max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ground profile that rises linearly to a peak of the given height halfway along a 10 meter span
+    fn peaked_ground(peak: f32) -> impl Fn(Vec3) -> Option<Vec3> {
+        move |point| {
+            Some(Vec3::new(
+                point.x,
+                peak * (1.0 - (point.x - 5.0).abs() / 5.0),
+                point.z,
+            ))
         }
     }
+
+    #[test]
+    fn flat_ground_needs_no_subdivisions() {
+        let flat = |point: Vec3| Some(Vec3::new(point.x, 0.0, point.z));
+        let subdivisions = fence_subdivisions(Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0), flat);
+
+        assert!(subdivisions.is_empty());
+    }
+
+    #[test]
+    fn even_slopes_need_no_subdivisions() {
+        let slope = |point: Vec3| Some(Vec3::new(point.x, point.x * 0.5, point.z));
+        let subdivisions = fence_subdivisions(Vec3::ZERO, Vec3::new(10.0, 5.0, 0.0), slope);
+
+        assert!(subdivisions.is_empty());
+    }
+
+    #[test]
+    fn uneven_ground_is_subdivided_at_its_peak() {
+        let to = Vec3::new(10.0, 0.0, 0.0);
+        let subdivisions = fence_subdivisions(Vec3::ZERO, to, peaked_ground(2.0));
+
+        assert_eq!(subdivisions.len(), 1);
+        assert!(subdivisions[0].distance(Vec3::new(5.0, 2.0, 0.0)) < 1e-4);
+    }
+
+    #[test]
+    fn small_bumps_are_ignored() {
+        let to = Vec3::new(10.0, 0.0, 0.0);
+        let subdivisions = fence_subdivisions(Vec3::ZERO, to, peaked_ground(MAX_FENCE_GAP / 2.0));
+
+        assert!(subdivisions.is_empty());
+    }
+
+    #[test]
+    fn short_fences_are_never_subdivided() {
+        let to = Vec3::new(MIN_FENCE_LENGTH * 1.5, 0.0, 0.0);
+        let bumpy = |point: Vec3| Some(Vec3::new(point.x, 1.0, point.z));
+
+        assert!(fence_subdivisions(Vec3::ZERO, to, bumpy).is_empty());
+    }
 }
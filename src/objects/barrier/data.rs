@@ -25,8 +25,17 @@ pub struct BarrierData {
 
     /// Per-meter cost of fence
     pub fence_cost: Currency,
+    /// Per-meter cost of maintaining fence each day
+    pub fence_upkeep: Currency,
     pub fence_model: Handle<GltfMesh>,
     pub fence_collider: Handle<Mesh>,
+
+    /// Height in meters that animals would need to climb or jump over
+    pub height: f32,
+    /// How well the barrier holds up against animals trying to break through, from 0 to 1
+    pub strength: f32,
+    /// How much of the view through the barrier guests can see, from 0 when solid to 1 when clear
+    pub transparency: f32,
 }
 
 impl UiDisplay for BarrierData {
@@ -56,16 +65,51 @@ impl FromWorld for BarrierLoader {
     fn from_world(world: &mut World) -> Self {
         // TODO: read from asset file in json or ron format
         let asset_server = world.resource::<AssetServer>();
-        let data = [BarrierData {
-            name: "Concrete Barrier".into(),
-            icon: asset_server.load("test.png"),
-            post_cost: 50.0,
-            post_model: asset_server.load("barriers/concrete_post.glb#Mesh0"),
-            post_collider: asset_server.load("barriers/concrete_post.glb#Mesh0/Primitive0"),
-            fence_cost: 10.0,
-            fence_model: asset_server.load("barriers/concrete_fence.glb#Mesh0"),
-            fence_collider: asset_server.load("barriers/concrete_fence.glb#Mesh0/Primitive0"),
-        }];
+        let data = [
+            BarrierData {
+                name: "Concrete Barrier".into(),
+                icon: asset_server.load("test.png"),
+                post_cost: 50.0,
+                post_model: asset_server.load("barriers/concrete_post.glb#Mesh0"),
+                post_collider: asset_server.load("barriers/concrete_post.glb#Mesh0/Primitive0"),
+                fence_cost: 10.0,
+                fence_upkeep: 0.05,
+                fence_model: asset_server.load("barriers/concrete_fence.glb#Mesh0"),
+                fence_collider: asset_server.load("barriers/concrete_fence.glb#Mesh0/Primitive0"),
+                height: 2.0,
+                strength: 0.9,
+                transparency: 0.0,
+            },
+            // TODO: replace placeholder concrete models once glass and wooden barrier models are made
+            BarrierData {
+                name: "Glass Barrier".into(),
+                icon: asset_server.load("test.png"),
+                post_cost: 120.0,
+                post_model: asset_server.load("barriers/concrete_post.glb#Mesh0"),
+                post_collider: asset_server.load("barriers/concrete_post.glb#Mesh0/Primitive0"),
+                fence_cost: 40.0,
+                fence_upkeep: 0.2,
+                fence_model: asset_server.load("barriers/concrete_fence.glb#Mesh0"),
+                fence_collider: asset_server.load("barriers/concrete_fence.glb#Mesh0/Primitive0"),
+                height: 2.5,
+                strength: 0.8,
+                transparency: 0.9,
+            },
+            BarrierData {
+                name: "Wooden Fence".into(),
+                icon: asset_server.load("test.png"),
+                post_cost: 20.0,
+                post_model: asset_server.load("barriers/concrete_post.glb#Mesh0"),
+                post_collider: asset_server.load("barriers/concrete_post.glb#Mesh0/Primitive0"),
+                fence_cost: 5.0,
+                fence_upkeep: 0.1,
+                fence_model: asset_server.load("barriers/concrete_fence.glb#Mesh0"),
+                fence_collider: asset_server.load("barriers/concrete_fence.glb#Mesh0/Primitive0"),
+                height: 1.2,
+                strength: 0.4,
+                transparency: 0.6,
+            },
+        ];
 
         let mut barrier_assets = world.resource_mut::<Assets<BarrierData>>();
         let barriers = data.map(|barrier| barrier_assets.add(barrier));
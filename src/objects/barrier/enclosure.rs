@@ -0,0 +1,148 @@
+use super::{
+    components::{BarrierFence, BarrierPost, PossibleBarrierCycle},
+    cycle::BarrierCycleUtil,
+    BarrierData,
+};
+use crate::{graph::UndirectedGraph, polygon::Polygon};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct EnclosurePlugin;
+impl Plugin for EnclosurePlugin {
+    fn build(&self, app: &mut App) {
+        // on_possible_cycle needs to ensure that the placed barriers are all actually spawned in when it runs
+        app.add_systems(PostUpdate, (refresh_enclosures, on_possible_cycle).chain());
+    }
+}
+
+/// Area fully surrounded by a cycle of barriers, which animals can be kept within
+#[derive(Component)]
+pub struct Enclosure {
+    /// Barrier that the enclosure was built from
+    pub barrier: Handle<BarrierData>,
+    /// Posts making up the boundary of the enclosure, in order
+    pub posts: Vec<Entity>,
+    /// Shape of the boundary on the XZ-plane
+    pub polygon: Polygon,
+}
+
+impl Enclosure {
+    /// Returns true if the given point on the XZ-plane is inside of the enclosure
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.polygon.contains(point)
+    }
+}
+
+/// Largest detour allowed between two neighboring posts of an enclosure before its boundary is considered broken
+const SPAN_TOLERANCE: f32 = 0.01;
+
+/// Rebuilds enclosures whose posts have been moved, subdivided or removed, despawning those that are no longer closed
+fn refresh_enclosures(
+    mut commands: Commands,
+    cycle_util: BarrierCycleUtil,
+    posts: Query<(Ref<BarrierPost>, Ref<Transform>)>,
+    mut removed_fences: RemovedComponents<BarrierFence>,
+    mut enclosures: Query<(Entity, &mut Enclosure)>,
+) {
+    let fences_removed = removed_fences.iter().count() > 0;
+
+    for (entity, mut enclosure) in enclosures.iter_mut() {
+        let changed = fences_removed
+            || enclosure.posts.iter().any(|&post| match posts.get(post) {
+                Ok((post, transform)) => post.is_changed() || transform.is_changed(),
+                Err(_) => true,
+            });
+
+        if !changed {
+            continue;
+        }
+
+        let Some(boundary) = trace_boundary(&cycle_util, &enclosure.posts) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+
+        let points = boundary
+            .iter()
+            .filter_map(|&post| posts.get(post).ok())
+            .map(|(_, transform)| transform.translation.xz())
+            .collect::<Vec<_>>();
+
+        enclosure.posts = boundary;
+        enclosure.polygon = Polygon::new(points);
+    }
+}
+
+/// Follows the fences between each pair of neighboring posts in the boundary, picking up any posts added in between
+///
+/// Returns `None` if the boundary has been broken, and so no longer encloses anything
+fn trace_boundary(cycle_util: &BarrierCycleUtil, posts: &[Entity]) -> Option<Vec<Entity>> {
+    let mut boundary = Vec::new();
+
+    for (i, &from) in posts.iter().enumerate() {
+        let to = posts[(i + 1) % posts.len()];
+        let mut span = cycle_util.shortest_path(from, to)?;
+
+        // posts added by subdividing a fence lie along the straight line between its ends
+        let length = span
+            .windows(2)
+            .map(|pair| cycle_util.edge_cost(pair[0], pair[1]))
+            .sum::<f32>();
+        if length > cycle_util.edge_cost(from, to) + SPAN_TOLERANCE {
+            return None;
+        }
+
+        span.pop();
+        boundary.extend(span);
+    }
+
+    // a valid enclosure needs at least a triangle of posts
+    (boundary.len() >= 3).then_some(boundary)
+}
+
+/// Creates a new enclosure whenever placing a barrier closes a cycle that isn't already enclosed
+fn on_possible_cycle(
+    mut commands: Commands,
+    mut possible_cycles: EventReader<PossibleBarrierCycle>,
+    cycle_util: BarrierCycleUtil,
+    posts: Query<(&BarrierPost, &Transform)>,
+    enclosures: Query<&Enclosure>,
+) {
+    for possible_cycle in possible_cycles.iter() {
+        let Some(cycle) = cycle_util.get_cycle(possible_cycle.root) else { continue };
+
+        // the same cycle can be closed more than once, such as when snapping onto an existing enclosure
+        let mut sorted_cycle = cycle.clone();
+        sorted_cycle.sort();
+
+        let already_enclosed = enclosures.iter().any(|enclosure| {
+            let mut sorted_posts = enclosure.posts.clone();
+            sorted_posts.sort();
+            sorted_posts == sorted_cycle
+        });
+
+        if already_enclosed {
+            continue;
+        }
+
+        let Ok((root_post, _)) = posts.get(possible_cycle.root) else { continue };
+        let points = cycle
+            .iter()
+            .filter_map(|&post| posts.get(post).ok())
+            .map(|(_, transform)| transform.translation.xz())
+            .collect::<Vec<_>>();
+
+        // a valid enclosure needs at least a triangle of posts
+        if points.len() < 3 {
+            continue;
+        }
+
+        commands.spawn((
+            Enclosure {
+                barrier: root_post.data.clone(),
+                posts: cycle,
+                polygon: Polygon::new(points),
+            },
+            Name::new("Enclosure"),
+        ));
+    }
+}
@@ -3,9 +3,12 @@ use bevy::prelude::*;
 mod components;
 mod cycle;
 mod data;
+mod enclosure;
 mod placement;
 
+pub use components::{BarrierFence, BarrierPost, FenceCondition};
 pub use data::BarrierData;
+pub use enclosure::Enclosure;
 
 pub struct BarrierPlugin;
 impl Plugin for BarrierPlugin {
@@ -13,6 +16,7 @@ impl Plugin for BarrierPlugin {
         app.add_plugins((
             components::BarrierComponentPlugin,
             data::BarrierDataPlugin,
+            enclosure::EnclosurePlugin,
             placement::BarrierPlacementPlugin,
         ));
     }
@@ -1,13 +1,15 @@
 use super::components::{BarrierFence, BarrierPost, PossibleBarrierCycle};
 use crate::{
     camera::CursorRaycast,
+    land::Land,
     objects::{
         utility::{ColliderMesh, CollisionLayer, RenderGltf, RenderGltfMode},
         BarrierData, ObjectBundle,
     },
     placement::{PlacePreview, Preview, PreviewData},
+    terrain::SeatOnTerrain,
 };
-use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
 use bevy_rapier3d::prelude::*;
 
 #[derive(Clone)]
@@ -224,6 +226,7 @@ fn on_preview_place(
     mut commands: Commands,
     mut placements: EventReader<PlacePreview>,
     barriers: Res<Assets<BarrierData>>,
+    land: Res<Land>,
 
     mut possible_cycles: EventWriter<PossibleBarrierCycle>,
 
@@ -237,42 +240,45 @@ fn on_preview_place(
         let Some(barrier_data_handle) = set.p0().data().cloned() else { continue };
         let Some(barrier_data) = barriers.get(&barrier_data_handle) else { continue };
 
+        // barriers can only be placed on land owned by the zoo
+        let within_land = match set.p0().preview_status() {
+            BarrierPreviewStatus::None => false,
+
+            BarrierPreviewStatus::Post { post } => {
+                let posts = set.p1();
+                let (_, post_transform) = posts.get(post).unwrap();
+                land.is_owned(post_transform.translation.xz())
+            }
+
+            // the entire length of the fence must be within the zoo
+            BarrierPreviewStatus::Connecting { post, fence } => {
+                let fences = set.p2();
+                let (preview_fence, _) = fences.get(fence).unwrap();
+                let previous_post_entity = preview_fence.connection[0];
+
+                let posts = set.p1();
+                let (_, previous_transform) = posts.get(previous_post_entity).unwrap();
+                let (_, post_transform) = posts.get(post).unwrap();
+
+                land.is_owned_between(
+                    previous_transform.translation.xz(),
+                    post_transform.translation.xz(),
+                )
+            }
+        };
+
+        if !within_land {
+            continue;
+        }
+
         let placed_post = commands.spawn_empty().id();
         let placed_fence = commands.spawn_empty().id();
 
-        let mut placed_post_bundle = ObjectBundle {
-            object: BarrierPost {
-                data: barrier_data_handle.clone(),
-                fences: vec![],
-            },
-            spatial: SpatialBundle::default(),
-            gltf: RenderGltf {
-                handle: barrier_data.post_model.clone(),
-                mode: RenderGltfMode::Regular,
-            },
-            collider: ColliderMesh {
-                mesh: barrier_data.post_collider.clone(),
-                rb: RigidBody::Fixed,
-                membership: CollisionLayer::Object,
-            },
-        };
+        let mut placed_post_bundle = BarrierPost::bundle(&barrier_data_handle, barrier_data);
 
-        let mut placed_fence_bundle = ObjectBundle {
-            object: BarrierFence {
-                data: barrier_data_handle.clone(),
-                connection: [placed_post, placed_post], // IMPORTANT: overwrite this with whatever the fence should be connected to
-            },
-            spatial: SpatialBundle::default(),
-            gltf: RenderGltf {
-                handle: barrier_data.fence_model.clone(),
-                mode: RenderGltfMode::Regular,
-            },
-            collider: ColliderMesh {
-                mesh: barrier_data.fence_collider.clone(),
-                rb: RigidBody::Fixed,
-                membership: CollisionLayer::Object,
-            },
-        };
+        // IMPORTANT: overwrite this connection with whatever the fence should be connected to
+        let mut placed_fence_bundle =
+            BarrierFence::bundle(&barrier_data_handle, barrier_data, [placed_post, placed_post]);
 
         match set.p0().preview_status() {
             BarrierPreviewStatus::None => {
@@ -362,7 +368,7 @@ fn on_preview_place(
         }
 
         // finally insert general object bundles into post and fence entities, if they still exist
-        commands.try_insert(placed_post, placed_post_bundle);
+        commands.try_insert(placed_post, (placed_post_bundle, SeatOnTerrain));
         commands.try_insert(placed_fence, placed_fence_bundle);
     }
 }
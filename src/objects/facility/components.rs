@@ -0,0 +1,90 @@
+use super::FacilityData;
+use crate::{
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    zoo::ZooBalanceChange,
+    Currency,
+};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+pub struct FacilityComponentPlugin;
+impl Plugin for FacilityComponentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Simulation, deliver_stock);
+    }
+}
+
+/// Multiplier on the restock cost of items delivered to a sold out facility, rather than restocked by staff
+const DELIVERY_MARKUP: f32 = 1.5;
+
+/// Instance of a facility in the world, serving guests one queue at a time
+#[derive(Component)]
+pub struct Facility {
+    pub data: Handle<FacilityData>,
+    /// Items left to sell, or `None` if the facility never runs out
+    pub stock: Option<u32>,
+    /// Guests waiting to be served, in order of arrival
+    pub queue: VecDeque<Entity>,
+    /// Guests currently being served, along with the seconds until they are done
+    pub serving: Vec<(Entity, f32)>,
+    /// Total money earned from guests
+    pub revenue: Currency,
+}
+
+impl Facility {
+    /// Newly built facility, starting fully stocked
+    pub(super) fn new(data_handle: Handle<FacilityData>, data: &FacilityData) -> Self {
+        Self {
+            data: data_handle,
+            stock: data.stock,
+            queue: VecDeque::new(),
+            serving: Vec::new(),
+            revenue: 0.0,
+        }
+    }
+
+    /// Refills the facility to its full stock, returning the cost of the items added
+    pub fn restock(&mut self, data: &FacilityData) -> Currency {
+        let (Some(stock), Some(max_stock)) = (self.stock, data.stock) else { return 0.0 };
+
+        self.stock = Some(max_stock);
+        data.restock_cost * max_stock.saturating_sub(stock) as f32
+    }
+
+    /// Returns true if the facility has anything left to serve guests with
+    pub fn is_open(&self) -> bool {
+        self.stock != Some(0)
+    }
+
+    /// Number of guests either waiting or being served
+    pub fn occupancy(&self) -> usize {
+        self.queue.len() + self.serving.len()
+    }
+}
+
+/// Has a delivery refill every sold out facility at the start of each day, so that they never close for good
+fn deliver_stock(
+    clock: Res<ZooClock>,
+    facility_data: Res<Assets<FacilityData>>,
+    mut facilities: Query<&mut Facility>,
+
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    if !clock.is_new_day() {
+        return;
+    }
+
+    for mut facility in facilities.iter_mut() {
+        if facility.is_open() {
+            continue;
+        }
+        let Some(data) = facility_data.get(&facility.data) else { continue };
+
+        let cost = facility.restock(data) * DELIVERY_MARKUP;
+        balance_changes.send(ZooBalanceChange {
+            amount: -cost,
+            category: LedgerCategory::Supplies,
+        });
+    }
+}
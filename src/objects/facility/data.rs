@@ -0,0 +1,167 @@
+use crate::{ui::UiDisplay, Currency, CurrencyFormat};
+use bevy::{
+    gltf::GltfMesh,
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+};
+
+pub struct FacilityDataPlugin;
+impl Plugin for FacilityDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<FacilityData>()
+            .init_resource::<FacilityLoader>();
+    }
+}
+
+#[derive(TypeUuid, TypePath)]
+#[uuid = "c3e1a7d2-58b4-4f0e-9d6a-71f2b8e4c5a3"]
+pub struct FacilityData {
+    pub name: String,
+    pub icon: Handle<Image>,
+
+    pub cost: Currency,
+    /// Stars the zoo needs to be rated before the facility can be bought
+    pub rating: f32,
+    /// The model that will be rendered for this facility
+    pub model: Handle<GltfMesh>,
+    /// The single collider mesh for this facility
+    pub collider: Handle<Mesh>,
+
+    pub kind: FacilityKind,
+    /// Number of guests that can be served at once
+    pub capacity: usize,
+    /// Seconds taken to serve a single guest
+    pub service_time: f32,
+    /// Most items the facility can hold, or `None` if it never runs out
+    pub stock: Option<u32>,
+    /// Price charged to each guest served
+    pub price: Currency,
+    /// Cost of restocking a single item
+    pub restock_cost: Currency,
+    /// Cost of running the facility each day
+    pub upkeep: Currency,
+}
+
+/// What a facility provides for guests
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FacilityKind {
+    FoodStand,
+    DrinkStand,
+    Toilet,
+    Bench,
+    GiftShop,
+}
+
+impl UiDisplay for FacilityData {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        match self.rating > 0.0 {
+            true => format!("{}, {} stars", self.cost.comma_separated(), self.rating),
+            false => self.cost.comma_separated(),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct FacilityLoader {
+    _facilities: Vec<Handle<FacilityData>>,
+}
+
+impl FromWorld for FacilityLoader {
+    fn from_world(world: &mut World) -> Self {
+        // TODO: read from asset file in json or ron format
+        // TODO: replace placeholder rock models once facility models are made
+        let asset_server = world.resource::<AssetServer>();
+        let data = [
+            FacilityData {
+                name: "Burger Stand".into(),
+                icon: asset_server.load("test.png"),
+                cost: 800.0,
+                rating: 0.0,
+                model: asset_server.load("nature/rocks.glb#Mesh6"),
+                collider: asset_server.load("nature/rocks.glb#Mesh6/Primitive0"),
+                kind: FacilityKind::FoodStand,
+                capacity: 2,
+                service_time: 4.0,
+                stock: Some(50),
+                price: 8.0,
+                restock_cost: 3.0,
+                upkeep: 7.0,
+            },
+            FacilityData {
+                name: "Drink Stand".into(),
+                icon: asset_server.load("test.png"),
+                cost: 600.0,
+                rating: 0.0,
+                model: asset_server.load("nature/rocks.glb#Mesh7"),
+                collider: asset_server.load("nature/rocks.glb#Mesh7/Primitive0"),
+                kind: FacilityKind::DrinkStand,
+                capacity: 2,
+                service_time: 3.0,
+                stock: Some(60),
+                price: 4.0,
+                restock_cost: 1.0,
+                upkeep: 5.0,
+            },
+            FacilityData {
+                name: "Toilets".into(),
+                icon: asset_server.load("test.png"),
+                cost: 1000.0,
+                rating: 0.0,
+                model: asset_server.load("nature/rocks.glb#Mesh8"),
+                collider: asset_server.load("nature/rocks.glb#Mesh8/Primitive0"),
+                kind: FacilityKind::Toilet,
+                capacity: 4,
+                service_time: 8.0,
+                stock: None,
+                price: 0.0,
+                restock_cost: 0.0,
+                upkeep: 8.0,
+            },
+            FacilityData {
+                name: "Bench".into(),
+                icon: asset_server.load("test.png"),
+                cost: 100.0,
+                rating: 0.0,
+                model: asset_server.load("nature/rocks.glb#Mesh0"),
+                collider: asset_server.load("nature/rocks.glb#Mesh0/Primitive0"),
+                kind: FacilityKind::Bench,
+                capacity: 2,
+                service_time: 15.0,
+                stock: None,
+                price: 0.0,
+                restock_cost: 0.0,
+                upkeep: 0.5,
+            },
+            FacilityData {
+                name: "Gift Shop".into(),
+                icon: asset_server.load("test.png"),
+                cost: 1500.0,
+                rating: 2.0,
+                model: asset_server.load("nature/rocks.glb#Mesh5"),
+                collider: asset_server.load("nature/rocks.glb#Mesh5/Primitive0"),
+                kind: FacilityKind::GiftShop,
+                capacity: 3,
+                service_time: 10.0,
+                stock: Some(30),
+                price: 15.0,
+                restock_cost: 6.0,
+                upkeep: 10.0,
+            },
+        ];
+
+        let mut facility_assets = world.resource_mut::<Assets<FacilityData>>();
+        let facilities = data.map(|facility| facility_assets.add(facility));
+
+        Self {
+            _facilities: facilities.into(),
+        }
+    }
+}
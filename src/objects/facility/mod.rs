@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+mod components;
+mod data;
+mod placement;
+
+pub use components::Facility;
+pub use data::{FacilityData, FacilityKind};
+
+pub struct FacilityPlugin;
+impl Plugin for FacilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            components::FacilityComponentPlugin,
+            data::FacilityDataPlugin,
+            placement::FacilityPlacementPlugin,
+        ));
+    }
+}
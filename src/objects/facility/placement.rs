@@ -0,0 +1,133 @@
+use super::{components::Facility, FacilityData};
+use crate::{
+    camera::CursorRaycast,
+    finance::LedgerCategory,
+    land::Land,
+    objects::{
+        utility::{ColliderMesh, CollisionLayer, RenderGltf, RenderGltfMode},
+        ObjectBundle,
+    },
+    placement::{PlacePreview, Preview, PreviewData},
+    rating::ZooRating,
+    terrain::SeatOnTerrain,
+    zoo::{Zoo, ZooBalanceChange},
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+use bevy_rapier3d::prelude::*;
+
+pub struct FacilityPlacementPlugin;
+impl Plugin for FacilityPlacementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (handle_preview_movement, on_preview_place));
+    }
+}
+
+impl PreviewData for Handle<FacilityData> {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        let facility_handle = self.clone();
+
+        commands.add(|world: &mut World| {
+            // get the facility data from assets collection
+            let facilities = world.resource::<Assets<FacilityData>>();
+            let facility_data = facilities.get(&facility_handle).unwrap();
+
+            // spawn facility preview
+            world.spawn((
+                ObjectBundle {
+                    object: Facility::new(facility_handle, facility_data),
+                    spatial: SpatialBundle::default(),
+                    gltf: RenderGltf {
+                        handle: facility_data.model.clone(),
+                        mode: RenderGltfMode::Preview,
+                    },
+                    collider: ColliderMesh {
+                        mesh: facility_data.collider.clone(),
+                        rb: RigidBody::Fixed,
+                        membership: CollisionLayer::None,
+                    },
+                },
+                Preview {
+                    cost: facility_data.cost,
+                },
+            ));
+        });
+    }
+}
+
+/// Facility preview that follows the cursor, hidden while it isn't over the ground
+type FacilityPreview<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut Transform, &'static mut Visibility),
+    (With<Facility>, With<Preview>),
+>;
+
+fn handle_preview_movement(cursor: CursorRaycast, mut preview: FacilityPreview) {
+    if let Ok((mut transform, mut visibility)) = preview.get_single_mut() {
+        match cursor.ground_point() {
+            Some(position) => {
+                transform.translation = position;
+                *visibility = Visibility::Visible;
+            }
+
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// Resources that are checked before a facility can be bought
+#[derive(SystemParam)]
+struct FacilityPurchasing<'w> {
+    facilities: Res<'w, Assets<FacilityData>>,
+    land: Res<'w, Land>,
+    zoo: Res<'w, Zoo>,
+    rating: Res<'w, ZooRating>,
+}
+
+fn on_preview_place(
+    mut commands: Commands,
+    purchasing: FacilityPurchasing,
+
+    mut placements: EventReader<PlacePreview>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+    preview: Query<(&Facility, &Transform), With<Preview>>,
+) {
+    for _ in placements.iter() {
+        let Ok((preview, transform)) = preview.get_single() else { continue };
+        let Some(facility_data) = purchasing.facilities.get(&preview.data) else { continue };
+
+        // facilities can only be placed on land owned by the zoo, and must be affordable
+        if !purchasing.land.is_owned(transform.translation.xz())
+            || purchasing.zoo.balance() < facility_data.cost
+        {
+            continue;
+        }
+
+        // better facilities are only unlocked once the zoo is rated highly enough
+        if !purchasing.rating.meets(facility_data.rating) {
+            continue;
+        }
+
+        commands.spawn((
+            ObjectBundle {
+                object: Facility::new(preview.data.clone(), facility_data),
+                spatial: SpatialBundle::from_transform(*transform),
+                gltf: RenderGltf {
+                    handle: facility_data.model.clone(),
+                    mode: RenderGltfMode::Regular,
+                },
+                collider: ColliderMesh {
+                    mesh: facility_data.collider.clone(),
+                    rb: RigidBody::Fixed,
+                    membership: CollisionLayer::Object,
+                },
+            },
+            SeatOnTerrain,
+        ));
+
+        balance_changes.send(ZooBalanceChange {
+            amount: -facility_data.cost,
+            category: LedgerCategory::Construction,
+        });
+    }
+}
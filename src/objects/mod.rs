@@ -1,24 +1,39 @@
 use bevy::prelude::*;
 
+mod animal;
 mod barrier;
+mod facility;
+mod path;
 mod prop;
 
 pub mod utility;
 
-pub use barrier::BarrierData;
-pub use prop::PropData;
+pub use animal::{
+    Animal, AnimalBehavior, AnimalData, AnimalHealth, AnimalMarket, AnimalNeeds, AnimalTradeTools,
+    AnimalWelfare, Escaped, Food, PopulationRecords, RecaptureAnimal, TreatAnimal,
+};
+pub use barrier::{BarrierData, BarrierFence, BarrierPost, Enclosure, FenceCondition};
+pub use facility::{Facility, FacilityData, FacilityKind};
+pub use path::{PathData, PathGraph};
+pub use prop::{BinContents, FoodSupply, Prop, PropData, PropFeature};
 
 pub struct ObjectPlugin;
 impl Plugin for ObjectPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((prop::PropPlugin, barrier::BarrierPlugin))
-            .add_systems(
-                Update,
-                (
-                    utility::handle_mesh_changes,
-                    utility::handle_collider_changes,
-                ),
-            );
+        app.add_plugins((
+            animal::AnimalPlugin,
+            prop::PropPlugin,
+            barrier::BarrierPlugin,
+            facility::FacilityPlugin,
+            path::PathPlugin,
+        ))
+        .add_systems(
+            Update,
+            (
+                utility::handle_mesh_changes,
+                utility::handle_collider_changes,
+            ),
+        );
     }
 }
 
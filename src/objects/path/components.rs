@@ -0,0 +1,129 @@
+use super::PathData;
+use crate::terrain::SeatOnTerrain;
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct PathComponentPlugin;
+impl Plugin for PathComponentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (mark_segment_movement, handle_segment_movement).chain(),
+        );
+    }
+}
+
+/// Height that paths are raised above the ground, so that they don't flicker against the terrain
+const PATH_OFFSET: f32 = 0.03;
+
+/// Point where path segments meet, either at a junction, a bend or the end of a path
+#[derive(Component, Clone)]
+pub struct PathNode {
+    /// List of all connecting segment entities
+    pub segments: Vec<Entity>,
+}
+
+/// Straight piece of path between two nodes
+#[derive(Component, Clone)]
+pub struct PathSegment {
+    pub data: Handle<PathData>,
+    /// Connection between nodes that this segment serves
+    pub connection: [Entity; 2],
+}
+
+impl PathNode {
+    /// Spawns a node at the given position, with a square of path beneath it to fill in any gaps at bends
+    pub(super) fn spawn(
+        commands: &mut Commands,
+        entity: Entity,
+        data: &PathData,
+        position: Vec3,
+        segments: Vec<Entity>,
+    ) {
+        commands
+            .entity(entity)
+            .insert((
+                PathNode { segments },
+                SpatialBundle::from_transform(Transform::from_translation(position)),
+                SeatOnTerrain,
+                Name::new("Path Node"),
+            ))
+            .with_children(|parent| {
+                parent.spawn(PbrBundle {
+                    mesh: data.mesh.clone(),
+                    material: data.material.clone(),
+                    transform: Transform {
+                        translation: Vec3::Y * PATH_OFFSET,
+                        scale: Vec3::new(data.width, 1.0, data.width),
+                        ..default()
+                    },
+                    ..default()
+                });
+            });
+    }
+}
+
+impl PathSegment {
+    /// Spawns a segment between the given nodes, which is positioned once both nodes exist
+    pub(super) fn spawn(
+        commands: &mut Commands,
+        entity: Entity,
+        data_handle: &Handle<PathData>,
+        data: &PathData,
+        connection: [Entity; 2],
+    ) {
+        commands.entity(entity).insert((
+            PathSegment {
+                data: data_handle.clone(),
+                connection,
+            },
+            PbrBundle {
+                mesh: data.mesh.clone(),
+                material: data.material.clone(),
+                ..default()
+            },
+            Name::new("Path Segment"),
+        ));
+    }
+}
+
+/// Marks a segment as changed if either of its connected nodes are moved
+fn mark_segment_movement(
+    moved_nodes: Query<&PathNode, Changed<Transform>>,
+    mut segments: Query<&mut PathSegment>,
+) {
+    for moved_node in moved_nodes.iter() {
+        for &segment_entity in moved_node.segments.iter() {
+            if let Ok(mut segment) = segments.get_mut(segment_entity) {
+                segment.set_changed();
+            }
+        }
+    }
+}
+
+/// Handles stretching the segment between its nodes if its `PathSegment` component has been changed
+fn handle_segment_movement(
+    paths: Res<Assets<PathData>>,
+    mut moved_segments: Query<(&PathSegment, &mut Transform), Changed<PathSegment>>,
+    all_nodes: Query<&Transform, (With<PathNode>, Without<PathSegment>)>,
+) {
+    for (segment, mut transform) in moved_segments.iter_mut() {
+        // get positions of both nodes
+        let Ok(from) = all_nodes.get(segment.connection[0]).map(|t| t.translation) else {
+            continue;
+        };
+        let Ok(to) = all_nodes.get(segment.connection[1]).map(|t| t.translation) else { continue };
+        let Some(data) = paths.get(&segment.data) else { continue };
+
+        // and calculate transform values for this segment
+        let delta = to - from;
+        let midpoint = from.lerp(to, 0.5);
+        let yaw = -f32::atan2(delta.z, delta.x);
+        let pitch = f32::atan2(delta.y, delta.xz().length());
+
+        *transform = Transform {
+            translation: midpoint + Vec3::Y * PATH_OFFSET,
+            rotation: Quat::from_rotation_y(yaw) * Quat::from_rotation_z(pitch),
+            scale: Vec3::new(delta.length(), 1.0, data.width),
+        };
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{ui::UiDisplay, Currency, CurrencyFormat};
+use bevy::{
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+};
+
+pub struct PathDataPlugin;
+impl Plugin for PathDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<PathData>().init_resource::<PathLoader>();
+    }
+}
+
+#[derive(TypeUuid, TypePath)]
+#[uuid = "9b6f3c1a-2d4e-4f7b-8a1c-5e3d2b7f4a90"]
+pub struct PathData {
+    pub name: String,
+    pub icon: Handle<Image>,
+
+    /// Per-meter cost of path
+    pub cost: Currency,
+    /// Width of the path in meters
+    pub width: f32,
+
+    /// Flat square mesh of unit size that is stretched along each path segment
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl UiDisplay for PathData {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        format!("{}/m", self.cost.comma_separated())
+    }
+}
+
+#[derive(Resource)]
+struct PathLoader {
+    _paths: Vec<Handle<PathData>>,
+}
+
+impl FromWorld for PathLoader {
+    fn from_world(world: &mut World) -> Self {
+        // TODO: read from asset file in json or ron format
+        let icon = world.resource::<AssetServer>().load("test.png");
+
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(
+            shape::Plane {
+                size: 1.0,
+                subdivisions: 0,
+            }
+            .into(),
+        );
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let data = [
+            PathData {
+                name: "Dirt Path".into(),
+                icon: icon.clone(),
+                cost: 5.0,
+                width: 2.0,
+                mesh: mesh.clone(),
+                material: materials.add(Color::rgb(0.55, 0.42, 0.28).into()),
+            },
+            PathData {
+                name: "Paved Path".into(),
+                icon: icon.clone(),
+                cost: 12.0,
+                width: 3.0,
+                mesh: mesh.clone(),
+                material: materials.add(Color::rgb(0.6, 0.6, 0.6).into()),
+            },
+            PathData {
+                name: "Promenade".into(),
+                icon,
+                cost: 20.0,
+                width: 5.0,
+                mesh,
+                material: materials.add(Color::rgb(0.82, 0.76, 0.64).into()),
+            },
+        ];
+
+        let mut path_assets = world.resource_mut::<Assets<PathData>>();
+        let paths = data.map(|path| path_assets.add(path));
+
+        Self {
+            _paths: paths.into(),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::components::{PathNode, PathSegment};
+use crate::graph::UndirectedGraph;
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+
+/// Utility system parameter for finding routes along the zoo's paths
+#[derive(SystemParam)]
+pub struct PathGraph<'w, 's> {
+    nodes: Query<'w, 's, (Entity, &'static PathNode, &'static Transform)>,
+    segments: Query<'w, 's, &'static PathSegment>,
+}
+
+impl<'w, 's> UndirectedGraph for PathGraph<'w, 's> {
+    type Node = Entity;
+
+    fn neighbors(&self, node: Entity) -> Vec<Entity> {
+        match self.nodes.get(node) {
+            // get all neighboring nodes from this node's segment connections
+            Ok((_, path_node, _)) => path_node
+                .segments
+                .iter()
+                .filter_map(|&segment_entity| self.segments.get(segment_entity).ok())
+                .filter_map(|segment| {
+                    segment
+                        .connection
+                        .iter()
+                        .find(|&&connect| connect != node)
+                        .copied()
+                })
+                .collect::<Vec<_>>(),
+
+            // if the entity given is not a node, return an empty list
+            Err(_) => vec![],
+        }
+    }
+
+    fn edge_cost(&self, from: Entity, to: Entity) -> f32 {
+        match (self.position(from), self.position(to)) {
+            (Some(from), Some(to)) => from.distance(to),
+            _ => f32::INFINITY,
+        }
+    }
+}
+
+impl<'w, 's> PathGraph<'w, 's> {
+    /// Return the position of the given node on the XZ-plane
+    pub fn position(&self, node: Entity) -> Option<Vec2> {
+        self.nodes
+            .get(node)
+            .ok()
+            .map(|(_, _, transform)| transform.translation.xz())
+    }
+
+    /// Return the node closest to the given point on the XZ-plane
+    pub fn nearest_node(&self, point: Vec2) -> Option<Entity> {
+        self.nodes
+            .iter()
+            .map(|(entity, _, transform)| (entity, transform.translation.xz().distance(point)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(entity, _)| entity)
+    }
+
+    /// Return every node of the path network, in no particular order
+    pub fn nodes(&self) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        self.nodes
+            .iter()
+            .map(|(entity, _, transform)| (entity, transform.translation.xz()))
+    }
+
+    /// Finds the shortest walk along the paths between two points on the XZ-plane
+    ///
+    /// The route joins the path network at the node nearest to each point, and ends at the given destination
+    pub fn route(&self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        let start = self.nearest_node(from)?;
+        let goal = self.nearest_node(to)?;
+
+        let mut points = self
+            .shortest_path(start, goal)?
+            .into_iter()
+            .filter_map(|node| self.position(node))
+            .collect::<Vec<_>>();
+        points.push(to);
+
+        Some(points)
+    }
+}
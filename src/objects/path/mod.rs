@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+mod components;
+mod data;
+mod graph;
+mod placement;
+
+pub use data::PathData;
+pub use graph::PathGraph;
+
+pub struct PathPlugin;
+impl Plugin for PathPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            components::PathComponentPlugin,
+            data::PathDataPlugin,
+            placement::PathPlacementPlugin,
+        ));
+    }
+}
@@ -0,0 +1,322 @@
+use super::{
+    components::{PathNode, PathSegment},
+    PathData,
+};
+use crate::{
+    camera::CursorRaycast,
+    finance::LedgerCategory,
+    land::Land,
+    placement::{PlacePreview, Preview, PreviewData},
+    zoo::{Zoo, ZooBalanceChange},
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+
+pub struct PathPlacementPlugin;
+impl Plugin for PathPlacementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (update_preview, on_preview_place).chain());
+    }
+}
+
+// constants for path placement
+/// Distance from an existing node or segment that a placed point will snap onto it
+const SNAP_DISTANCE: f32 = 1.0;
+/// Longest segment that is placed, longer paths are split so that they follow the terrain
+const MAX_SEGMENT_LENGTH: f32 = 4.0;
+
+/// Existing part of the path network that a placed point connects to
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PathAttachment {
+    /// Joins onto an existing node
+    Node(Entity),
+    /// Splits an existing segment to create a junction
+    Segment(Entity),
+}
+
+/// Point that a path is being placed from or to
+#[derive(Clone, Copy, Debug)]
+struct PathAnchor {
+    position: Vec3,
+    attachment: Option<PathAttachment>,
+}
+
+/// Preview for the path tool, which is drawn between the previously placed point and the cursor
+#[derive(Component)]
+struct PathPreview {
+    data: Handle<PathData>,
+    /// Point placed by the previous click, if the path has been started
+    start: Option<PathAnchor>,
+    /// Point currently under the cursor
+    end: Option<PathAnchor>,
+}
+
+impl PreviewData for Handle<PathData> {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpatialBundle::default(),
+            PathPreview {
+                data: self.clone(),
+                start: None,
+                end: None,
+            },
+            Preview { cost: 0.0 },
+        ));
+    }
+}
+
+/// Finds where a point placed at the given position would attach to the existing path network
+fn snap_anchor(
+    position: Vec3,
+    nodes: &Query<(Entity, &Transform), With<PathNode>>,
+    segments: &Query<(Entity, &PathSegment)>,
+) -> PathAnchor {
+    let point = position.xz();
+
+    // nodes are preferred over segments so that junctions aren't placed right beside each other
+    let nearest_node = nodes
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation))
+        .map(|(entity, node_position)| (entity, node_position, node_position.xz().distance(point)))
+        .filter(|&(_, _, distance)| distance <= SNAP_DISTANCE)
+        .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b));
+
+    if let Some((entity, node_position, _)) = nearest_node {
+        return PathAnchor {
+            position: node_position,
+            attachment: Some(PathAttachment::Node(entity)),
+        };
+    }
+
+    let nearest_segment = segments
+        .iter()
+        .filter_map(|(entity, segment)| {
+            let Ok((_, from)) = nodes.get(segment.connection[0]) else { return None };
+            let Ok((_, to)) = nodes.get(segment.connection[1]) else { return None };
+            let (from, to) = (from.translation, to.translation);
+
+            // project onto the segment, keeping away from its ends where the nodes would be snapped to instead
+            let along = to.xz() - from.xz();
+            let t = (point - from.xz()).dot(along) / along.length_squared();
+            let projected = from.lerp(to, t.clamp(0.0, 1.0));
+
+            Some((entity, projected, projected.xz().distance(point)))
+        })
+        .filter(|&(_, _, distance)| distance <= SNAP_DISTANCE)
+        .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b));
+
+    match nearest_segment {
+        Some((entity, projected, _)) => PathAnchor {
+            position: projected,
+            attachment: Some(PathAttachment::Segment(entity)),
+        },
+
+        None => PathAnchor {
+            position,
+            attachment: None,
+        },
+    }
+}
+
+/// Moves the preview's end point to the cursor and draws the path that would be placed
+fn update_preview(
+    cursor: CursorRaycast,
+    paths: Res<Assets<PathData>>,
+    nodes: Query<(Entity, &Transform), With<PathNode>>,
+    segments: Query<(Entity, &PathSegment)>,
+    mut preview: Query<(&mut PathPreview, &mut Preview)>,
+    mut gizmos: Gizmos,
+) {
+    const PREVIEW_COLOR: Color = Color::WHITE;
+
+    let Ok((mut path_preview, mut preview)) = preview.get_single_mut() else { return };
+    let Some(data) = paths.get(&path_preview.data) else { return };
+
+    path_preview.end = cursor
+        .ground_point()
+        .map(|point| snap_anchor(point, &nodes, &segments));
+
+    let Some(end) = path_preview.end else { return };
+    let half_width = data.width / 2.0;
+
+    // outline a square at the cursor before the path has been started
+    let Some(start) = path_preview.start else {
+        let corners = [
+            Vec3::new(-half_width, 0.0, -half_width),
+            Vec3::new(half_width, 0.0, -half_width),
+            Vec3::new(half_width, 0.0, half_width),
+            Vec3::new(-half_width, 0.0, half_width),
+            Vec3::new(-half_width, 0.0, -half_width),
+        ];
+        gizmos.linestrip(corners.map(|corner| end.position + corner), PREVIEW_COLOR);
+
+        preview.cost = 0.0;
+        return;
+    };
+
+    // outline both edges of the path between the start and the cursor
+    let direction = (end.position.xz() - start.position.xz()).normalize_or_zero();
+    let side = Vec3::new(-direction.y, 0.0, direction.x) * half_width;
+
+    gizmos.line(start.position + side, end.position + side, PREVIEW_COLOR);
+    gizmos.line(start.position - side, end.position - side, PREVIEW_COLOR);
+
+    preview.cost = data.cost * start.position.xz().distance(end.position.xz());
+}
+
+/// Nodes that have been created by placing a path but not yet spawned, along with their connecting segments
+#[derive(Default)]
+struct PendingNodes {
+    nodes: Vec<(Entity, Vec3, Vec<Entity>)>,
+}
+
+impl PendingNodes {
+    /// Connects the segment to the given node, whether it already exists or is still pending
+    fn connect(&mut self, node: Entity, segment: Entity, existing: &mut Query<&mut PathNode>) {
+        match self.nodes.iter_mut().find(|(entity, _, _)| *entity == node) {
+            Some((_, _, segments)) => segments.push(segment),
+            None => {
+                if let Ok(mut existing_node) = existing.get_mut(node) {
+                    existing_node.segments.push(segment);
+                }
+            }
+        }
+    }
+}
+
+/// Resources that are checked before a path can be bought
+#[derive(SystemParam)]
+struct PathPurchasing<'w> {
+    paths: Res<'w, Assets<PathData>>,
+    land: Res<'w, Land>,
+    zoo: Res<'w, Zoo>,
+}
+
+/// Places the previewed path, or starts a new one if there is no previous point
+fn on_preview_place(
+    mut commands: Commands,
+    purchasing: PathPurchasing,
+    mut preview: Query<(&mut PathPreview, &Preview)>,
+    mut nodes: Query<&mut PathNode>,
+    mut segments: Query<&mut PathSegment>,
+
+    mut placements: EventReader<PlacePreview>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    for _ in placements.iter() {
+        let Ok((mut path_preview, preview)) = preview.get_single_mut() else { continue };
+        let Some(end) = path_preview.end else { continue };
+        let Some(data) = purchasing.paths.get(&path_preview.data) else { continue };
+        let data_handle = path_preview.data.clone();
+
+        // the first click only marks where the path starts
+        let Some(start) = path_preview.start else {
+            if purchasing.land.is_owned(end.position.xz()) {
+                path_preview.start = Some(end);
+            }
+
+            continue;
+        };
+
+        // paths must be entirely within the zoo, and there must be somewhere to go
+        if !purchasing
+            .land
+            .is_owned_between(start.position.xz(), end.position.xz())
+            || start.position.xz().distance(end.position.xz()) < SNAP_DISTANCE
+            || purchasing.zoo.balance() < preview.cost
+        {
+            continue;
+        }
+
+        let mut pending = PendingNodes::default();
+
+        // finds or creates the node for each end of the path
+        let mut resolve = |anchor: PathAnchor, pending: &mut PendingNodes| match anchor.attachment {
+            Some(PathAttachment::Node(node)) => node,
+
+            Some(PathAttachment::Segment(segment_entity)) => {
+                // split the segment in two, with the new node in between
+                let node = commands.spawn_empty().id();
+                let new_segment = commands.spawn_empty().id();
+
+                let Ok(mut segment) = segments.get_mut(segment_entity) else { return node };
+                let end_node = segment.connection[1];
+                segment.connection[1] = node;
+
+                // end node is now connected to the new segment instead of the split one
+                if let Ok(mut end) = nodes.get_mut(end_node) {
+                    for connected_segment in end.segments.iter_mut() {
+                        if *connected_segment == segment_entity {
+                            *connected_segment = new_segment;
+                        }
+                    }
+                }
+
+                PathSegment::spawn(
+                    &mut commands,
+                    new_segment,
+                    &segment.data,
+                    data,
+                    [node, end_node],
+                );
+                pending
+                    .nodes
+                    .push((node, anchor.position, vec![segment_entity, new_segment]));
+
+                node
+            }
+
+            None => {
+                let node = commands.spawn_empty().id();
+                pending.nodes.push((node, anchor.position, vec![]));
+
+                node
+            }
+        };
+
+        let start_node = resolve(start, &mut pending);
+        let end_node = resolve(end, &mut pending);
+
+        // long paths are split into shorter segments with nodes in between
+        let pieces = (start.position.xz().distance(end.position.xz()) / MAX_SEGMENT_LENGTH)
+            .ceil()
+            .max(1.0) as usize;
+
+        let mut chain = vec![start_node];
+        for i in 1..pieces {
+            let node = commands.spawn_empty().id();
+            let position = start.position.lerp(end.position, i as f32 / pieces as f32);
+            pending.nodes.push((node, position, vec![]));
+            chain.push(node);
+        }
+        chain.push(end_node);
+
+        for pair in chain.windows(2) {
+            let segment = commands.spawn_empty().id();
+            PathSegment::spawn(
+                &mut commands,
+                segment,
+                &data_handle,
+                data,
+                [pair[0], pair[1]],
+            );
+
+            pending.connect(pair[0], segment, &mut nodes);
+            pending.connect(pair[1], segment, &mut nodes);
+        }
+
+        for (entity, position, node_segments) in pending.nodes {
+            PathNode::spawn(&mut commands, entity, data, position, node_segments);
+        }
+
+        balance_changes.send(ZooBalanceChange {
+            amount: -preview.cost,
+            category: LedgerCategory::Construction,
+        });
+
+        // continue placing from the end of this path
+        path_preview.start = Some(PathAnchor {
+            position: end.position,
+            attachment: Some(PathAttachment::Node(end_node)),
+        });
+    }
+}
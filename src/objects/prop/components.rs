@@ -6,3 +6,16 @@ use bevy::prelude::*;
 pub struct Prop {
     pub data: Handle<PropData>,
 }
+
+/// Food left in a prop that feeds animals, which keepers have to refill
+#[derive(Component)]
+pub struct FoodSupply {
+    /// Fraction of a full supply left, from 0 when empty to 1 when full
+    pub remaining: f32,
+}
+
+/// Trash thrown away in a bin, which janitors have to empty
+#[derive(Component, Default)]
+pub struct BinContents {
+    pub amount: u32,
+}
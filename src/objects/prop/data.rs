@@ -1,4 +1,4 @@
-use crate::{ui::UiDisplay, Currency, CurrencyFormat};
+use crate::{objects::Food, ui::UiDisplay, Currency, CurrencyFormat};
 use bevy::{
     gltf::GltfMesh,
     prelude::*,
@@ -19,10 +19,45 @@ pub struct PropData {
     pub icon: Handle<Image>,
 
     pub cost: Currency,
+    /// Cost of looking after the prop each day
+    pub upkeep: Currency,
     /// The model that will be rendered for this prop
     pub model: Handle<GltfMesh>,
     /// The single collider mesh for this prop
     pub collider: Handle<Mesh>,
+    /// What the prop provides to animals sharing its enclosure
+    pub feature: PropFeature,
+    /// Square meters of ground the prop covers
+    pub coverage: f32,
+}
+
+/// Purpose that a prop serves for the animals sharing its enclosure
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PropFeature {
+    /// Counts towards an enclosure's rock coverage
+    Rock,
+    /// Counts towards an enclosure's foliage coverage
+    Foliage,
+    /// Feeds animals whose diet includes the food
+    Food(Food),
+    /// Lets animals drink
+    Water,
+    /// Gives animals somewhere comfortable to rest
+    Shelter,
+    /// Lets guests throw away their trash, until it holds the given number of items
+    Bin { capacity: u32 },
+}
+
+impl PropFeature {
+    /// Returns true if the prop is part of the scenery rather than something animals make use of
+    pub fn is_nature(self) -> bool {
+        matches!(self, PropFeature::Rock | PropFeature::Foliage)
+    }
+
+    /// Returns true if the prop is placed for guests rather than animals
+    pub fn is_for_guests(self) -> bool {
+        matches!(self, PropFeature::Bin { .. })
+    }
 }
 
 impl UiDisplay for PropData {
@@ -53,64 +88,164 @@ impl FromWorld for PropLoader {
                 name: "Dark Rock 1".into(),
                 icon: asset_server.load("test.png"),
                 cost: 20.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh0"),
                 collider: asset_server.load("nature/rocks.glb#Mesh0/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 2.0,
             },
             PropData {
                 name: "Dark Rock 2".into(),
                 icon: asset_server.load("test.png"),
                 cost: 25.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh1"),
                 collider: asset_server.load("nature/rocks.glb#Mesh1/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 3.0,
             },
             PropData {
                 name: "Dark Rock 3".into(),
                 icon: asset_server.load("test.png"),
                 cost: 30.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh2"),
                 collider: asset_server.load("nature/rocks.glb#Mesh2/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 4.0,
             },
             PropData {
                 name: "Light Rock 1".into(),
                 icon: asset_server.load("test.png"),
                 cost: 20.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh3"),
                 collider: asset_server.load("nature/rocks.glb#Mesh3/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 2.0,
             },
             PropData {
                 name: "Light Rock 2".into(),
                 icon: asset_server.load("test.png"),
                 cost: 25.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh4"),
                 collider: asset_server.load("nature/rocks.glb#Mesh4/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 3.0,
             },
             PropData {
                 name: "Light Rock 3".into(),
                 icon: asset_server.load("test.png"),
                 cost: 30.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh5"),
                 collider: asset_server.load("nature/rocks.glb#Mesh5/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 4.0,
             },
             PropData {
                 name: "Sandy Rock 1".into(),
                 icon: asset_server.load("test.png"),
                 cost: 20.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh6"),
                 collider: asset_server.load("nature/rocks.glb#Mesh6/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 2.0,
             },
             PropData {
                 name: "Sandy Rock 2".into(),
                 icon: asset_server.load("test.png"),
                 cost: 25.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh7"),
                 collider: asset_server.load("nature/rocks.glb#Mesh7/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 3.0,
             },
             PropData {
                 name: "Sandy Rock 3".into(),
                 icon: asset_server.load("test.png"),
                 cost: 30.0,
+                upkeep: 0.0,
                 model: asset_server.load("nature/rocks.glb#Mesh8"),
                 collider: asset_server.load("nature/rocks.glb#Mesh8/Primitive0"),
+                feature: PropFeature::Rock,
+                coverage: 4.0,
+            },
+            // TODO: replace placeholder rock models once foliage models are made
+            PropData {
+                name: "Shrub".into(),
+                icon: asset_server.load("test.png"),
+                cost: 35.0,
+                upkeep: 0.2,
+                model: asset_server.load("nature/rocks.glb#Mesh0"),
+                collider: asset_server.load("nature/rocks.glb#Mesh0/Primitive0"),
+                feature: PropFeature::Foliage,
+                coverage: 3.0,
+            },
+            PropData {
+                name: "Tree".into(),
+                icon: asset_server.load("test.png"),
+                cost: 80.0,
+                upkeep: 0.5,
+                model: asset_server.load("nature/rocks.glb#Mesh5"),
+                collider: asset_server.load("nature/rocks.glb#Mesh5/Primitive0"),
+                feature: PropFeature::Foliage,
+                coverage: 12.0,
+            },
+            // TODO: replace placeholder rock models once animal feature models are made
+            PropData {
+                name: "Hay Feeder".into(),
+                icon: asset_server.load("test.png"),
+                cost: 150.0,
+                upkeep: 1.0,
+                model: asset_server.load("nature/rocks.glb#Mesh7"),
+                collider: asset_server.load("nature/rocks.glb#Mesh7/Primitive0"),
+                feature: PropFeature::Food(Food::Plants),
+                coverage: 2.0,
+            },
+            PropData {
+                name: "Meat Feeder".into(),
+                icon: asset_server.load("test.png"),
+                cost: 200.0,
+                upkeep: 1.5,
+                model: asset_server.load("nature/rocks.glb#Mesh1"),
+                collider: asset_server.load("nature/rocks.glb#Mesh1/Primitive0"),
+                feature: PropFeature::Food(Food::Meat),
+                coverage: 2.0,
+            },
+            PropData {
+                name: "Water Trough".into(),
+                icon: asset_server.load("test.png"),
+                cost: 120.0,
+                upkeep: 1.0,
+                model: asset_server.load("nature/rocks.glb#Mesh4"),
+                collider: asset_server.load("nature/rocks.glb#Mesh4/Primitive0"),
+                feature: PropFeature::Water,
+                coverage: 2.0,
+            },
+            PropData {
+                name: "Animal Shelter".into(),
+                icon: asset_server.load("test.png"),
+                cost: 400.0,
+                upkeep: 2.0,
+                model: asset_server.load("nature/rocks.glb#Mesh2"),
+                collider: asset_server.load("nature/rocks.glb#Mesh2/Primitive0"),
+                feature: PropFeature::Shelter,
+                coverage: 9.0,
+            },
+            // TODO: replace placeholder rock model once a bin model is made
+            PropData {
+                name: "Litter Bin".into(),
+                icon: asset_server.load("test.png"),
+                cost: 60.0,
+                upkeep: 0.5,
+                model: asset_server.load("nature/rocks.glb#Mesh3"),
+                collider: asset_server.load("nature/rocks.glb#Mesh3/Primitive0"),
+                feature: PropFeature::Bin { capacity: 20 },
+                coverage: 0.5,
             },
         ];
 
@@ -4,8 +4,8 @@ mod components;
 mod data;
 mod placement;
 
-pub use components::Prop;
-pub use data::PropData;
+pub use components::{BinContents, FoodSupply, Prop};
+pub use data::{PropData, PropFeature};
 
 pub struct PropPlugin;
 impl Plugin for PropPlugin {
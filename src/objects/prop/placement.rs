@@ -1,13 +1,15 @@
-use super::components::Prop;
+use super::components::{BinContents, FoodSupply, Prop};
 use crate::{
     camera::CursorRaycast,
+    land::Land,
     objects::{
         utility::{ColliderMesh, CollisionLayer, RenderGltf, RenderGltfMode},
-        ObjectBundle, PropData,
+        ObjectBundle, PropData, PropFeature,
     },
     placement::{PlacePreview, Preview, PreviewData},
+    terrain::SeatOnTerrain,
 };
-use bevy::prelude::*;
+use bevy::{math::Vec3Swizzles, prelude::*};
 use bevy_rapier3d::prelude::*;
 
 pub struct PropPlacementPlugin;
@@ -68,6 +70,7 @@ fn handle_preview_movement(
 fn on_preview_place(
     mut commands: Commands,
     props: Res<Assets<PropData>>,
+    land: Res<Land>,
 
     mut placements: EventReader<PlacePreview>,
     preview: Query<(&Prop, &Transform), With<Preview>>,
@@ -76,23 +79,42 @@ fn on_preview_place(
         let Ok((preview, transform)) = preview.get_single() else { return };
         let Some(prop_data) = props.get(&preview.data) else { return };
 
-        commands.spawn(ObjectBundle {
-            object: Prop {
-                data: preview.data.clone(),
-            },
-            spatial: SpatialBundle {
-                transform: transform.clone(),
-                ..default()
-            },
-            gltf: RenderGltf {
-                handle: prop_data.model.clone(),
-                mode: RenderGltfMode::Regular,
-            },
-            collider: ColliderMesh {
-                mesh: prop_data.collider.clone(),
-                rb: RigidBody::Fixed,
-                membership: CollisionLayer::Object,
+        // props can only be placed on land owned by the zoo
+        if !land.is_owned(transform.translation.xz()) {
+            continue;
+        }
+
+        let mut prop = commands.spawn((
+            ObjectBundle {
+                object: Prop {
+                    data: preview.data.clone(),
+                },
+                spatial: SpatialBundle {
+                    transform: transform.clone(),
+                    ..default()
+                },
+                gltf: RenderGltf {
+                    handle: prop_data.model.clone(),
+                    mode: RenderGltfMode::Regular,
+                },
+                collider: ColliderMesh {
+                    mesh: prop_data.collider.clone(),
+                    rb: RigidBody::Fixed,
+                    membership: CollisionLayer::Object,
+                },
             },
-        });
+            SeatOnTerrain,
+        ));
+
+        // food props start out fully stocked, and bins start out empty
+        match prop_data.feature {
+            PropFeature::Food(_) => {
+                prop.insert(FoodSupply { remaining: 1.0 });
+            }
+            PropFeature::Bin { .. } => {
+                prop.insert(BinContents::default());
+            }
+            _ => (),
+        }
     }
 }
@@ -1,4 +1,4 @@
-use bevy::{gltf::GltfMesh, pbr::NotShadowCaster, prelude::*};
+use bevy::{ecs::system::SystemParam, gltf::GltfMesh, pbr::NotShadowCaster, prelude::*};
 use bevy_rapier3d::prelude::*;
 
 /// Component that allows a glTF mesh to be rendered by supplying its handle and some settings
@@ -83,6 +83,14 @@ pub enum CollisionLayer {
     None,
     Ground,
     Object,
+    Water,
+}
+
+impl CollisionLayer {
+    /// Query filter that only includes colliders that are members of this layer
+    pub fn filter(self) -> QueryFilter<'static> {
+        QueryFilter::default().groups(CollisionGroups::new(self.into(), self.into()))
+    }
 }
 
 impl From<CollisionLayer> for Group {
@@ -91,6 +99,7 @@ impl From<CollisionLayer> for Group {
             CollisionLayer::None => Group::NONE,
             CollisionLayer::Ground => Group::GROUP_1,
             CollisionLayer::Object => Group::GROUP_2,
+            CollisionLayer::Water => Group::GROUP_3,
         }
     }
 }
@@ -120,3 +129,29 @@ pub fn handle_collider_changes(
         ));
     }
 }
+
+/// System parameter to find the ground surface at arbitrary points in the world
+#[derive(SystemParam)]
+pub struct GroundRaycast<'w> {
+    rapier: Res<'w, RapierContext>,
+}
+
+impl<'w> GroundRaycast<'w> {
+    /// Height that downward raycasts start from, should be above any possible ground
+    const CAST_HEIGHT: f32 = 1000.0;
+
+    /// Return the point on the ground directly above or below the given point, if ground exists there
+    pub fn ground_below(&self, point: Vec3) -> Option<Vec3> {
+        let origin = Vec3::new(point.x, Self::CAST_HEIGHT, point.z);
+
+        let (_, distance) = self.rapier.cast_ray(
+            origin,
+            Vec3::NEG_Y,
+            f32::MAX,
+            true,
+            CollisionLayer::Ground.filter(),
+        )?;
+
+        Some(origin + Vec3::NEG_Y * distance)
+    }
+}
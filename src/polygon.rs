@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+/// Closed polygon on the XZ-plane, such as the area within an enclosure
+#[derive(Clone, Debug, Default)]
+pub struct Polygon {
+    pub points: Vec<Vec2>,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<Vec2>) -> Self {
+        Self { points }
+    }
+
+    /// Iterates over each edge of the polygon, including the edge closing the last point back to the first
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.points
+            .iter()
+            .copied()
+            .zip(self.points.iter().copied().cycle().skip(1))
+    }
+
+    /// Returns true if the point is within the polygon, using the even-odd rule
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.edges()
+            .filter(|(a, b)| {
+                // count edges crossed by a ray cast from the point in the +x direction
+                (a.y > point.y) != (b.y > point.y)
+                    && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Shortest distance from the point to any edge of the polygon
+    pub fn distance_to_boundary(&self, point: Vec2) -> f32 {
+        self.edges()
+            .map(|(a, b)| {
+                // project the point onto the edge, clamped between its ends
+                let edge = b - a;
+                let t = ((point - a).dot(edge) / edge.length_squared()).clamp(0.0, 1.0);
+                point.distance(a + edge * t)
+            })
+            .fold(f32::MAX, f32::min)
+    }
+
+    /// Area enclosed by the polygon in square meters
+    pub fn area(&self) -> f32 {
+        // shoelace formula
+        let double_area = self
+            .edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<f32>();
+
+        double_area.abs() / 2.0
+    }
+
+    /// Smallest rectangle containing all points of the polygon
+    pub fn bounds(&self) -> Rect {
+        let empty = Rect {
+            min: Vec2::splat(f32::MAX),
+            max: Vec2::splat(f32::MIN),
+        };
+
+        self.points
+            .iter()
+            .fold(empty, |rect, &point| rect.union_point(point))
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    clock::{Simulation, ZooClock, DAYS_PER_MONTH},
+    guest::GuestHappiness,
+    litter::Litter,
+    objects::{Animal, AnimalData, AnimalWelfare, PopulationRecords},
+    placement::Preview,
+    zoo::ZooLogMessage,
+};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use std::collections::{HashSet, VecDeque};
+
+pub struct RatingPlugin;
+impl Plugin for RatingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ZooRating>()
+            .add_systems(Simulation, update_rating);
+    }
+}
+
+// constants for the zoo rating
+const MAX_STARS: f32 = 5.0;
+/// Share of the rating given to each part of it, adding up to 1
+const WELFARE_WEIGHT: f32 = 0.3;
+const HAPPINESS_WEIGHT: f32 = 0.25;
+const CLEANLINESS_WEIGHT: f32 = 0.15;
+const VARIETY_WEIGHT: f32 = 0.15;
+const CONSERVATION_WEIGHT: f32 = 0.15;
+/// Pieces of litter lying around that make the whole zoo filthy
+const FILTHY_LITTER: f32 = 40.0;
+/// Conservation points needed for full marks, with a point for each birth
+const CONSERVATION_TARGET: f32 = 20.0;
+/// Conservation points given for each animal released into the wild
+const RELEASE_POINTS: f32 = 3.0;
+/// Days of ratings kept in the history
+const HISTORY_DAYS: usize = 360;
+/// Most guests allowed into a zoo without any stars
+const BASE_CAPACITY: usize = 20;
+/// Additional guests allowed into the zoo for each star it is rated
+const CAPACITY_PER_STAR: f32 = 30.0;
+
+/// Scores from 0 to 1 that make up the zoo's rating
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RatingBreakdown {
+    pub welfare: f32,
+    pub happiness: f32,
+    pub cleanliness: f32,
+    /// How many of the catalog's species are on exhibit
+    pub variety: f32,
+    /// Animals bred and released into the wild
+    pub conservation: f32,
+}
+
+impl RatingBreakdown {
+    /// Combines each score into a rating from 0 to 5 stars
+    fn stars(&self) -> f32 {
+        let score = self.welfare * WELFARE_WEIGHT
+            + self.happiness * HAPPINESS_WEIGHT
+            + self.cleanliness * CLEANLINESS_WEIGHT
+            + self.variety * VARIETY_WEIGHT
+            + self.conservation * CONSERVATION_WEIGHT;
+
+        score * MAX_STARS
+    }
+}
+
+/// Zoo-wide star rating, which limits how many guests visit and what the zoo can buy
+#[derive(Resource, Default)]
+pub struct ZooRating {
+    pub breakdown: RatingBreakdown,
+    /// Rating at the start of each day, oldest first
+    history: VecDeque<f32>,
+}
+
+impl ZooRating {
+    /// Returns true once the zoo has been rated for the first time
+    pub fn is_rated(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    /// Returns the current rating, from 0 to 5 stars
+    pub fn stars(&self) -> f32 {
+        self.history.back().copied().unwrap_or_default()
+    }
+
+    /// Returns how much the rating has changed over the last month
+    pub fn monthly_trend(&self) -> f32 {
+        let month_ago = self
+            .history
+            .iter()
+            .rev()
+            .nth(DAYS_PER_MONTH as usize)
+            .or(self.history.front());
+
+        self.stars() - month_ago.copied().unwrap_or_default()
+    }
+
+    /// Returns the most guests that can be within the zoo at once
+    pub fn guest_capacity(&self) -> usize {
+        BASE_CAPACITY + (self.stars() * CAPACITY_PER_STAR) as usize
+    }
+
+    /// Returns true if the zoo is rated highly enough for catalog items requiring the given stars
+    pub fn meets(&self, required_stars: f32) -> bool {
+        self.stars() >= required_stars
+    }
+}
+
+/// Parts of the zoo that are looked at when rating it
+#[derive(SystemParam)]
+struct RatingSources<'w, 's> {
+    welfare: Res<'w, AnimalWelfare>,
+    happiness: Res<'w, GuestHappiness>,
+    records: Res<'w, PopulationRecords>,
+    animal_data: Res<'w, Assets<AnimalData>>,
+    animals: Query<'w, 's, &'static Animal, Without<Preview>>,
+    litter: Query<'w, 's, (), With<Litter>>,
+}
+
+impl RatingSources<'_, '_> {
+    /// Scores each part of the zoo as it currently stands
+    fn breakdown(&self) -> RatingBreakdown {
+        // an empty zoo has nobody to disappoint yet
+        let happiness = match self.happiness.guests {
+            0 => 0.5,
+            _ => self.happiness.average,
+        };
+
+        let cleanliness = 1.0 - (self.litter.iter().count() as f32 / FILTHY_LITTER).min(1.0);
+
+        let species = self
+            .animals
+            .iter()
+            .map(|animal| animal.data.id())
+            .collect::<HashSet<_>>();
+        let variety = species.len() as f32 / self.animal_data.len().max(1) as f32;
+
+        let points = self.records.released as f32 * RELEASE_POINTS + self.records.births as f32;
+        let conservation = (points / CONSERVATION_TARGET).min(1.0);
+
+        RatingBreakdown {
+            welfare: self.welfare.average,
+            happiness,
+            cleanliness,
+            variety,
+            conservation,
+        }
+    }
+}
+
+/// Rates the zoo at the start of each day, first rating it as soon as it opens
+fn update_rating(
+    clock: Res<ZooClock>,
+    sources: RatingSources,
+    mut rating: ResMut<ZooRating>,
+
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    if rating.is_rated() && !clock.is_new_day() {
+        return;
+    }
+
+    let previous = rating.stars();
+    rating.breakdown = sources.breakdown();
+    let stars = rating.breakdown.stars();
+    rating.history.push_back(stars);
+    if rating.history.len() > HISTORY_DAYS {
+        rating.history.pop_front();
+    }
+
+    // let the player know whenever the zoo gains or loses a whole star
+    if rating.history.len() > 1 && stars.floor() != previous.floor() {
+        log_messages.send(ZooLogMessage {
+            message: format!("The zoo is now rated {:.0} stars", stars.floor()),
+        });
+    }
+}
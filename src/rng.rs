@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use std::ops::Range;
+
+/// Seed used by the zoo's random number generator when no other is given
+const DEFAULT_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// Small deterministic random number generator, so that simulations play out identically from the same seed
+#[derive(Resource, Clone, Debug)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl Default for SeededRng {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random 64-bit number, using the splitmix64 algorithm
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number from 0 up to but not including 1
+    pub fn next_f32(&mut self) -> f32 {
+        // the top 24 bits fit exactly within an f32's mantissa
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns a random number within the given range
+    pub fn range(&mut self, range: Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.next_f32()
+    }
+
+    /// Returns true with the given probability, from 0 to 1
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// Returns a random item from the slice, or `None` if it is empty
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        match items.is_empty() {
+            true => None,
+            false => items.get((self.next_u64() % items.len() as u64) as usize),
+        }
+    }
+
+    /// Creates a new generator seeded from this one, so that it can be advanced independently
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.next_u64())
+    }
+}
@@ -0,0 +1,220 @@
+use super::{
+    roles::StaffRole,
+    tasks::{self, PendingTask, StaffTask, StaffTasks, TaskCompleted},
+};
+use crate::{
+    clock::{Simulation, ZooClock},
+    land::Land,
+    objects::Enclosure,
+    rng::SeededRng,
+    terrain::Terrain,
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct StaffBehaviorPlugin;
+impl Plugin for StaffBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        // tasks are finished before the queue is next updated, so that they aren't posted again
+        app.add_systems(Simulation, update_behavior.before(tasks::on_task_completed));
+    }
+}
+
+// constants for staff behavior
+/// Walking speed of staff in meters per second
+const WALK_SPEED: f32 = 1.8;
+/// Distance from a destination that staff are considered to have arrived at it
+const ARRIVAL_DISTANCE: f32 = 1.0;
+/// Range of seconds that staff stand around for between patrols
+const IDLE_DURATION: std::ops::Range<f32> = 2.0..6.0;
+/// Number of random points tried when looking for somewhere to patrol to
+const PATROL_ATTEMPTS: usize = 16;
+
+/// Part of the zoo that a member of staff looks after
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StaffAssignment {
+    /// Only takes on tasks belonging to the enclosure
+    Enclosure(Entity),
+    /// Takes on any task within the circle
+    Area { center: Vec2, radius: f32 },
+}
+
+impl StaffAssignment {
+    /// Returns true if the task falls within the assignment
+    ///
+    /// Staff assigned to an enclosure that has since been removed take on tasks anywhere
+    fn covers(&self, task: &PendingTask, enclosures: &Query<&Enclosure>) -> bool {
+        match *self {
+            StaffAssignment::Enclosure(enclosure) => {
+                task.enclosure == Some(enclosure) || enclosures.get(enclosure).is_err()
+            }
+
+            StaffAssignment::Area { center, radius } => task.position.distance(center) <= radius,
+        }
+    }
+}
+
+/// What a member of staff is currently doing
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StaffActivity {
+    /// Standing in place for the remaining number of seconds
+    Idle { remaining: f32 },
+    /// Walking around their assignment, looking for work
+    Patrol { destination: Vec2 },
+    /// Walking towards a claimed task
+    Travel { task: StaffTask },
+    /// Working on a task for the remaining number of seconds
+    Work { task: StaffTask, remaining: f32 },
+}
+
+/// Member of staff that takes on tasks from the queue within their assignment
+#[derive(Component)]
+pub struct Staff {
+    pub role: StaffRole,
+    pub assignment: StaffAssignment,
+    pub activity: StaffActivity,
+    rng: SeededRng,
+}
+
+impl Staff {
+    pub fn new(role: StaffRole, assignment: StaffAssignment, rng: SeededRng) -> Self {
+        Self {
+            role,
+            assignment,
+            activity: StaffActivity::Idle { remaining: 0.0 },
+            rng,
+        }
+    }
+
+    /// Activity for standing around for a random amount of time
+    fn idle(&mut self) -> StaffActivity {
+        StaffActivity::Idle {
+            remaining: self.rng.range(IDLE_DURATION),
+        }
+    }
+
+    /// Searches for a random point within the staff member's assignment to patrol to
+    fn patrol_point(
+        &mut self,
+        enclosures: &Query<&Enclosure>,
+        accept: impl Fn(Vec2) -> bool,
+    ) -> Option<Vec2> {
+        let (bounds, enclosure) = match self.assignment {
+            StaffAssignment::Enclosure(entity) => {
+                let enclosure = enclosures.get(entity).ok()?;
+                (enclosure.polygon.bounds(), Some(enclosure))
+            }
+
+            StaffAssignment::Area { center, radius } => (
+                Rect::from_center_half_size(center, Vec2::splat(radius)),
+                None,
+            ),
+        };
+
+        (0..PATROL_ATTEMPTS)
+            .map(|_| {
+                Vec2::new(
+                    self.rng.range(bounds.min.x..bounds.max.x),
+                    self.rng.range(bounds.min.y..bounds.max.y),
+                )
+            })
+            .find(|&point| {
+                accept(point) && enclosure.iter().all(|enclosure| enclosure.contains(point))
+            })
+    }
+}
+
+/// Moves staff between their tasks, claiming new ones from the queue whenever they are free
+fn update_behavior(
+    clock: Res<ZooClock>,
+    terrain: Res<Terrain>,
+    land: Res<Land>,
+    mut tasks: ResMut<StaffTasks>,
+    enclosures: Query<&Enclosure>,
+    mut staff: Query<(Entity, &mut Staff, &mut Transform)>,
+    mut completions: EventWriter<TaskCompleted>,
+) {
+    let delta = clock.delta_seconds();
+
+    for (entity, mut member, mut transform) in staff.iter_mut() {
+        let position = transform.translation.xz();
+
+        // free staff take on the nearest task within their assignment
+        if matches!(
+            member.activity,
+            StaffActivity::Idle { .. } | StaffActivity::Patrol { .. }
+        ) {
+            let assignment = member.assignment;
+            let claimed = tasks.claim(entity, member.role, position, |task| {
+                assignment.covers(task, &enclosures)
+            });
+
+            if let Some(task) = claimed {
+                member.activity = StaffActivity::Travel { task };
+            }
+        }
+
+        let destination = match member.activity {
+            StaffActivity::Idle { ref mut remaining } => {
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    member.activity =
+                        match member.patrol_point(&enclosures, |point| land.is_owned(point)) {
+                            Some(destination) => StaffActivity::Patrol { destination },
+                            None => member.idle(),
+                        };
+                }
+
+                continue;
+            }
+
+            StaffActivity::Patrol { destination } => destination,
+
+            // tasks can be dropped from the queue once they no longer need doing
+            StaffActivity::Travel { task } => match tasks.get(task) {
+                Some(pending) => pending.position,
+                None => {
+                    member.activity = member.idle();
+                    continue;
+                }
+            },
+
+            StaffActivity::Work {
+                task,
+                ref mut remaining,
+            } => {
+                *remaining -= delta;
+
+                if tasks.get(task).is_none() {
+                    member.activity = member.idle();
+                } else if *remaining <= 0.0 {
+                    tasks.finish(task);
+                    completions.send(TaskCompleted { task });
+                    member.activity = member.idle();
+                }
+
+                continue;
+            }
+        };
+
+        if position.distance(destination) <= ARRIVAL_DISTANCE {
+            member.activity = match member.activity {
+                StaffActivity::Travel { task } => StaffActivity::Work {
+                    task,
+                    remaining: task.work_time(),
+                },
+
+                _ => member.idle(),
+            };
+
+            continue;
+        }
+
+        // staff are allowed inside of enclosures, so they walk straight to wherever they are needed
+        let step = (destination - position).clamp_length_max(WALK_SPEED * delta);
+        let next = position + step;
+        let Some(height) = terrain.height_at(next) else { continue };
+
+        transform.translation = Vec3::new(next.x, height, next.y);
+        transform.rotation = Quat::from_rotation_y(-f32::atan2(step.y, step.x));
+    }
+}
@@ -0,0 +1,268 @@
+use super::{
+    behavior::{Staff, StaffAssignment},
+    roles::StaffRole,
+    tasks::StaffTasks,
+};
+use crate::{
+    camera::CursorRaycast,
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    land::Land,
+    objects::Enclosure,
+    placement::{PlacePreview, Preview, PreviewData},
+    rng::SeededRng,
+    terrain::SeatOnTerrain,
+    ui::UiDisplay,
+    zoo::{Zoo, ZooBalanceChange, ZooLogMessage},
+    CurrencyFormat,
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+
+pub struct StaffHiringPlugin;
+impl Plugin for StaffHiringPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StaffTools>()
+            .add_systems(Update, (draw_hiring_preview, on_staff_tool_place))
+            .add_systems(Simulation, pay_wages);
+    }
+}
+
+// constants for hiring staff
+/// Radius of the area that staff hired outside of an enclosure look after
+const PATROL_RADIUS: f32 = 20.0;
+/// Furthest distance from the cursor that a member of staff can be dismissed from
+const DISMISS_DISTANCE: f32 = 2.0;
+
+/// Ways that the zoo can manage its staff
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StaffAction {
+    /// Hires a new member of staff in the role, assigned to wherever they are placed
+    Hire(StaffRole),
+    /// Lets go of the member of staff nearest to the cursor
+    Dismiss,
+}
+
+/// Tool that can be selected from the ui to hire or dismiss staff
+#[derive(Clone)]
+pub struct StaffTool {
+    pub action: StaffAction,
+    pub icon: Handle<Image>,
+}
+
+impl UiDisplay for StaffTool {
+    fn name(&self) -> String {
+        match self.action {
+            StaffAction::Hire(role) => format!("Hire {}", role.name()),
+            StaffAction::Dismiss => "Dismiss Staff".into(),
+        }
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        match self.action {
+            StaffAction::Hire(role) => format!("{}/month", role.wage().comma_separated()),
+            StaffAction::Dismiss => "Stops their wages".into(),
+        }
+    }
+}
+
+impl PreviewData for StaffTool {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpatialBundle::default(),
+            StaffToolPreview {
+                action: self.action,
+            },
+            Preview { cost: 0.0 },
+        ));
+    }
+}
+
+/// Collection of all staff management tools
+#[derive(Resource)]
+pub struct StaffTools {
+    pub tools: Vec<StaffTool>,
+}
+
+impl FromWorld for StaffTools {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        let hire = StaffRole::ALL.map(|role| StaffTool {
+            action: StaffAction::Hire(role),
+            icon: asset_server.load("test.png"),
+        });
+        let dismiss = StaffTool {
+            action: StaffAction::Dismiss,
+            icon: asset_server.load("test.png"),
+        };
+
+        Self {
+            tools: hire.into_iter().chain([dismiss]).collect(),
+        }
+    }
+}
+
+/// Preview for the currently selected staff tool
+#[derive(Component)]
+struct StaffToolPreview {
+    action: StaffAction,
+}
+
+/// Assignment given to staff hired at the point, which is the enclosure it lies within or the area around it
+fn assignment_at(point: Vec2, enclosures: &Query<(Entity, &Enclosure)>) -> StaffAssignment {
+    match enclosures
+        .iter()
+        .find(|(_, enclosure)| enclosure.contains(point))
+    {
+        Some((entity, _)) => StaffAssignment::Enclosure(entity),
+        None => StaffAssignment::Area {
+            center: point,
+            radius: PATROL_RADIUS,
+        },
+    }
+}
+
+/// Outlines the part of the zoo that staff would be assigned to if hired at the cursor
+fn draw_hiring_preview(
+    mut gizmos: Gizmos,
+    cursor: CursorRaycast,
+    enclosures: Query<(Entity, &Enclosure)>,
+    preview: Query<&StaffToolPreview>,
+) {
+    let Ok(preview) = preview.get_single() else { return };
+    let StaffAction::Hire(role) = preview.action else { return };
+    let Some(point) = cursor.ground_point() else { return };
+
+    match assignment_at(point.xz(), &enclosures) {
+        StaffAssignment::Enclosure(entity) => {
+            let Ok((_, enclosure)) = enclosures.get(entity) else { return };
+            let points = &enclosure.polygon.points;
+            let height = point.y + 0.5;
+            gizmos.linestrip(
+                points
+                    .iter()
+                    .chain(points.first())
+                    .map(|corner| Vec3::new(corner.x, height, corner.y)),
+                role.color(),
+            );
+        }
+
+        StaffAssignment::Area { center, radius } => {
+            gizmos.circle(
+                Vec3::new(center.x, point.y + 0.5, center.y),
+                Vec3::Y,
+                radius,
+                role.color(),
+            );
+        }
+    }
+}
+
+/// Resources that are checked or updated when staff are hired and dismissed
+#[derive(SystemParam)]
+struct StaffHiring<'w, 's> {
+    land: Res<'w, Land>,
+    zoo: Res<'w, Zoo>,
+    rng: ResMut<'w, SeededRng>,
+    tasks: ResMut<'w, StaffTasks>,
+    enclosures: Query<'w, 's, (Entity, &'static Enclosure)>,
+}
+
+/// Hires staff at the cursor or dismisses the member of staff nearest to it
+fn on_staff_tool_place(
+    mut commands: Commands,
+    cursor: CursorRaycast,
+    mut hiring: StaffHiring,
+    staff: Query<(Entity, &Staff, &Transform)>,
+    preview: Query<&StaffToolPreview>,
+
+    mut placements: EventReader<PlacePreview>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    for _ in placements.iter() {
+        let Ok(preview) = preview.get_single() else { continue };
+        let Some(point) = cursor.ground_point() else { continue };
+        let position = point.xz();
+
+        match preview.action {
+            StaffAction::Hire(role) => {
+                // new staff need to be placed within the zoo, and the zoo needs to be able to pay their first wage
+                if !hiring.land.is_owned(position) || hiring.zoo.balance() < role.wage() {
+                    continue;
+                }
+
+                commands.spawn((
+                    SpatialBundle::from_transform(Transform::from_translation(point)),
+                    Staff::new(
+                        role,
+                        assignment_at(position, &hiring.enclosures),
+                        hiring.rng.fork(),
+                    ),
+                    SeatOnTerrain,
+                    Name::new(role.name()),
+                ));
+
+                log_messages.send(ZooLogMessage {
+                    message: format!(
+                        "Hired a {} for {} a month",
+                        role.name(),
+                        role.wage().comma_separated()
+                    ),
+                });
+            }
+
+            StaffAction::Dismiss => {
+                let nearest = staff
+                    .iter()
+                    .map(|(entity, member, transform)| {
+                        (
+                            entity,
+                            member,
+                            transform.translation.xz().distance(position),
+                        )
+                    })
+                    .filter(|&(.., distance)| distance <= DISMISS_DISTANCE)
+                    .min_by(|(.., a), (.., b)| a.total_cmp(b));
+
+                let Some((entity, member, _)) = nearest else { continue };
+
+                hiring.tasks.release(entity);
+                commands.entity(entity).despawn_recursive();
+
+                log_messages.send(ZooLogMessage {
+                    message: format!("Dismissed a {}", member.role.name()),
+                });
+            }
+        }
+    }
+}
+
+/// Pays every member of staff their wage once a month
+fn pay_wages(
+    clock: Res<ZooClock>,
+    staff: Query<&Staff>,
+
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+    mut log_messages: EventWriter<ZooLogMessage>,
+) {
+    if !clock.is_new_month() {
+        return;
+    }
+
+    let wages = staff.iter().map(|member| member.role.wage()).sum::<f32>();
+    if wages <= 0.0 {
+        return;
+    }
+
+    balance_changes.send(ZooBalanceChange {
+        amount: -wages,
+        category: LedgerCategory::Wages,
+    });
+    log_messages.send(ZooLogMessage {
+        message: format!("Paid {} in staff wages", wages.comma_separated()),
+    });
+}
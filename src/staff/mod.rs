@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+mod behavior;
+mod hiring;
+mod render;
+mod roles;
+mod tasks;
+
+pub use behavior::Staff;
+pub use hiring::StaffTools;
+pub use roles::StaffRole;
+pub use tasks::StaffTasks;
+
+pub struct StaffPlugin;
+impl Plugin for StaffPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            behavior::StaffBehaviorPlugin,
+            hiring::StaffHiringPlugin,
+            render::StaffRenderPlugin,
+            tasks::StaffTaskPlugin,
+        ));
+    }
+}
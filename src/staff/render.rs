@@ -0,0 +1,59 @@
+use super::{behavior::Staff, roles::StaffRole};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub struct StaffRenderPlugin;
+impl Plugin for StaffRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StaffAssets>()
+            .add_systems(Update, render_new_staff);
+    }
+}
+
+/// Shared mesh and uniform materials that staff are rendered with
+#[derive(Resource)]
+struct StaffAssets {
+    mesh: Handle<Mesh>,
+    uniforms: HashMap<StaffRole, Handle<StandardMaterial>>,
+}
+
+impl FromWorld for StaffAssets {
+    fn from_world(world: &mut World) -> Self {
+        // TODO: replace with proper staff models
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(
+            shape::Capsule {
+                radius: 0.25,
+                depth: 1.2,
+                ..default()
+            }
+            .into(),
+        );
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let uniforms = StaffRole::ALL
+            .into_iter()
+            .map(|role| (role, materials.add(role.color().into())))
+            .collect();
+
+        Self { mesh, uniforms }
+    }
+}
+
+/// Gives newly hired staff a model in their role's uniform
+fn render_new_staff(
+    mut commands: Commands,
+    staff_assets: Res<StaffAssets>,
+    new_staff: Query<(Entity, &Staff), Added<Staff>>,
+) {
+    for (entity, member) in new_staff.iter() {
+        commands.entity(entity).with_children(|parent| {
+            // raise the capsule so that it stands on the staff member's position
+            parent.spawn(PbrBundle {
+                mesh: staff_assets.mesh.clone(),
+                material: staff_assets.uniforms[&member.role].clone(),
+                transform: Transform::from_xyz(0.0, 0.85, 0.0),
+                ..default()
+            });
+        });
+    }
+}
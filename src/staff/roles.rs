@@ -0,0 +1,53 @@
+use crate::Currency;
+use bevy::prelude::*;
+
+/// Jobs that staff can be hired for, each handling its own kinds of tasks
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StaffRole {
+    /// Feeds animals, cleans their enclosures and catches any that escape
+    Zookeeper,
+    /// Treats ill animals
+    Vet,
+    /// Repairs worn down fences
+    Maintenance,
+    /// Keeps facilities stocked and the paths clean
+    Janitor,
+}
+
+impl StaffRole {
+    pub const ALL: [StaffRole; 4] = [
+        StaffRole::Zookeeper,
+        StaffRole::Vet,
+        StaffRole::Maintenance,
+        StaffRole::Janitor,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StaffRole::Zookeeper => "Zookeeper",
+            StaffRole::Vet => "Vet",
+            StaffRole::Maintenance => "Maintenance Worker",
+            StaffRole::Janitor => "Janitor",
+        }
+    }
+
+    /// Wage paid to each member of staff in this role every month
+    pub fn wage(&self) -> Currency {
+        match self {
+            StaffRole::Zookeeper => 800.0,
+            StaffRole::Vet => 1500.0,
+            StaffRole::Maintenance => 900.0,
+            StaffRole::Janitor => 600.0,
+        }
+    }
+
+    /// Color of the uniform that staff in this role wear
+    pub fn color(&self) -> Color {
+        match self {
+            StaffRole::Zookeeper => Color::rgb(0.3, 0.5, 0.2),
+            StaffRole::Vet => Color::rgb(0.9, 0.9, 0.9),
+            StaffRole::Maintenance => Color::rgb(0.9, 0.5, 0.1),
+            StaffRole::Janitor => Color::rgb(0.5, 0.3, 0.6),
+        }
+    }
+}
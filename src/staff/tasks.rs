@@ -0,0 +1,423 @@
+use super::{behavior::Staff, roles::StaffRole};
+use crate::{
+    clock::{Simulation, ZooClock},
+    finance::LedgerCategory,
+    litter::{Litter, LitterKind},
+    objects::{
+        AnimalBehavior, AnimalHealth, BarrierFence, BarrierPost, BinContents, Enclosure, Escaped,
+        Facility, FacilityData, FenceCondition, FoodSupply, Prop, PropData, PropFeature,
+        RecaptureAnimal, TreatAnimal,
+    },
+    placement::Preview,
+    zoo::ZooBalanceChange,
+    Currency,
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+
+pub struct StaffTaskPlugin;
+impl Plugin for StaffTaskPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StaffTasks>()
+            .add_event::<TaskCompleted>()
+            .add_systems(Simulation, (on_task_completed, post_tasks).chain());
+    }
+}
+
+// constants for staff tasks
+/// Seconds between the zoo being checked for work that needs doing
+const TASK_INTERVAL: f32 = 1.0;
+/// Food props are refilled once their supply drops below this
+const REFILL_THRESHOLD: f32 = 0.3;
+/// Fences are repaired once their condition drops below this
+const REPAIR_THRESHOLD: f32 = 0.6;
+/// Facilities are restocked once they have less than this fraction of their stock left
+const RESTOCK_THRESHOLD: f32 = 0.5;
+/// Bins are emptied once they are filled past this fraction of their capacity
+const EMPTY_THRESHOLD: f32 = 0.75;
+/// Seconds that a task can wait for someone to take it before the zoo is considered understaffed
+const OVERDUE_TIME: f32 = 60.0;
+/// Cost of the food used to refill a food prop
+const FEED_COST: Currency = 40.0;
+/// Cost of the materials used to repair a fence
+const REPAIR_COST: Currency = 50.0;
+
+/// Single piece of work for a member of staff
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StaffTask {
+    /// Refill a food prop
+    Feed { prop: Entity },
+    /// Catch an escaped animal and return it to its enclosure
+    Recapture { animal: Entity },
+    /// Treat an ill animal
+    Treat { animal: Entity },
+    /// Repair a worn down fence
+    Repair { fence: Entity },
+    /// Refill a facility's stock
+    Restock { facility: Entity },
+    /// Sweep up trash dropped by guests
+    Sweep { litter: Entity },
+    /// Clean up waste left by animals within their enclosure
+    MuckOut { litter: Entity },
+    /// Empty a bin that is filling up
+    EmptyBin { bin: Entity },
+}
+
+impl StaffTask {
+    /// Role of the staff that handle this task
+    pub fn role(&self) -> StaffRole {
+        match self {
+            StaffTask::Feed { .. } | StaffTask::Recapture { .. } | StaffTask::MuckOut { .. } => {
+                StaffRole::Zookeeper
+            }
+            StaffTask::Treat { .. } => StaffRole::Vet,
+            StaffTask::Repair { .. } => StaffRole::Maintenance,
+            StaffTask::Restock { .. } | StaffTask::Sweep { .. } | StaffTask::EmptyBin { .. } => {
+                StaffRole::Janitor
+            }
+        }
+    }
+
+    /// Seconds spent working on the task once at its location
+    pub fn work_time(&self) -> f32 {
+        match self {
+            StaffTask::Feed { .. } => 4.0,
+            StaffTask::Recapture { .. } => 3.0,
+            StaffTask::Treat { .. } => 6.0,
+            StaffTask::Repair { .. } => 8.0,
+            StaffTask::Restock { .. } => 5.0,
+            StaffTask::Sweep { .. } => 2.0,
+            StaffTask::MuckOut { .. } => 3.0,
+            StaffTask::EmptyBin { .. } => 3.0,
+        }
+    }
+}
+
+/// Task waiting to be done, along with where it needs doing
+pub struct PendingTask {
+    pub task: StaffTask,
+    pub position: Vec2,
+    /// Enclosure that the task belongs to, if any
+    pub enclosure: Option<Entity>,
+    /// Member of staff currently on their way to or working on the task
+    pub claimed_by: Option<Entity>,
+    /// Seconds that the task has been waiting for someone to take it
+    pub waiting: f32,
+}
+
+/// Queue of every task in the zoo that needs doing
+#[derive(Resource, Default)]
+pub struct StaffTasks {
+    tasks: Vec<PendingTask>,
+}
+
+impl StaffTasks {
+    pub fn get(&self, task: StaffTask) -> Option<&PendingTask> {
+        self.tasks.iter().find(|pending| pending.task == task)
+    }
+
+    /// Number of tasks for the role that nobody has taken yet, along with how many of those are overdue
+    pub fn unclaimed(&self, role: StaffRole) -> (usize, usize) {
+        let unclaimed = self
+            .tasks
+            .iter()
+            .filter(|pending| pending.task.role() == role && pending.claimed_by.is_none());
+
+        unclaimed.fold((0, 0), |(count, overdue), pending| {
+            (
+                count + 1,
+                overdue + (pending.waiting >= OVERDUE_TIME) as usize,
+            )
+        })
+    }
+
+    /// Claims the nearest unclaimed task for the role that the staff member is willing to take on
+    pub(super) fn claim(
+        &mut self,
+        staff: Entity,
+        role: StaffRole,
+        position: Vec2,
+        accept: impl Fn(&PendingTask) -> bool,
+    ) -> Option<StaffTask> {
+        let pending = self
+            .tasks
+            .iter_mut()
+            .filter(|pending| {
+                pending.task.role() == role && pending.claimed_by.is_none() && accept(pending)
+            })
+            .min_by(|a, b| {
+                a.position
+                    .distance_squared(position)
+                    .total_cmp(&b.position.distance_squared(position))
+            })?;
+
+        pending.claimed_by = Some(staff);
+        Some(pending.task)
+    }
+
+    /// Frees up any task claimed by the staff member, so that someone else can take it on
+    pub(super) fn release(&mut self, staff: Entity) {
+        for pending in self.tasks.iter_mut() {
+            if pending.claimed_by == Some(staff) {
+                pending.claimed_by = None;
+            }
+        }
+    }
+
+    /// Removes a task from the queue once it has been done
+    pub(super) fn finish(&mut self, task: StaffTask) {
+        self.tasks.retain(|pending| pending.task != task);
+    }
+}
+
+/// Event sent once a member of staff has finished working on a task
+#[derive(Event)]
+pub(super) struct TaskCompleted {
+    pub task: StaffTask,
+}
+
+/// Animals that might need treating or recapturing, along with where they are
+type TaskAnimals<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static AnimalHealth,
+        &'static Transform,
+        Option<&'static AnimalBehavior>,
+        Option<&'static Escaped>,
+    ),
+    Without<Preview>,
+>;
+
+/// Everything around the zoo that staff might need to look after
+#[derive(SystemParam)]
+struct TaskSources<'w, 's> {
+    facility_data: Res<'w, Assets<FacilityData>>,
+    prop_data: Res<'w, Assets<PropData>>,
+    enclosures: Query<'w, 's, (Entity, &'static Enclosure)>,
+    food: Query<'w, 's, (Entity, &'static FoodSupply, &'static Transform)>,
+    animals: TaskAnimals<'w, 's>,
+    fences: Query<'w, 's, (Entity, &'static BarrierFence, &'static FenceCondition)>,
+    posts: Query<'w, 's, &'static Transform, With<BarrierPost>>,
+    facilities: Query<'w, 's, (Entity, &'static Facility, &'static Transform), Without<Preview>>,
+    litter: Query<'w, 's, (Entity, &'static Litter, &'static Transform)>,
+    bins: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Prop,
+            &'static BinContents,
+            &'static Transform,
+        ),
+    >,
+}
+
+impl TaskSources<'_, '_> {
+    /// Every task that currently needs doing, along with where it is and the enclosure it is within
+    fn needed(&self) -> Vec<(StaffTask, Vec2, Option<Entity>)> {
+        let enclosure_at = |point: Vec2| {
+            self.enclosures
+                .iter()
+                .find(|(_, enclosure)| enclosure.contains(point))
+                .map(|(entity, _)| entity)
+        };
+
+        let mut needed = Vec::new();
+
+        for (prop, supply, transform) in self.food.iter() {
+            if supply.remaining < REFILL_THRESHOLD {
+                let position = transform.translation.xz();
+                needed.push((StaffTask::Feed { prop }, position, enclosure_at(position)));
+            }
+        }
+
+        for (animal, health, transform, behavior, escaped) in self.animals.iter() {
+            let position = transform.translation.xz();
+
+            // escaped animals are caught before anything else, and only treated once back in their enclosure
+            if let Some(escaped) = escaped {
+                needed.push((
+                    StaffTask::Recapture { animal },
+                    position,
+                    Some(escaped.enclosure),
+                ));
+            } else if health.illness.is_some_and(|illness| !illness.treated) {
+                let enclosure = behavior.map(|behavior| behavior.enclosure);
+                needed.push((StaffTask::Treat { animal }, position, enclosure));
+            }
+        }
+
+        for (fence, barrier, condition) in self.fences.iter() {
+            if condition.condition >= REPAIR_THRESHOLD {
+                continue;
+            }
+
+            let [a, b] = barrier.connection;
+            let (Ok(from), Ok(to)) = (self.posts.get(a), self.posts.get(b)) else { continue };
+            let position = (from.translation.xz() + to.translation.xz()) / 2.0;
+
+            // fences belong to the enclosure they form part of the boundary of
+            let enclosure = self
+                .enclosures
+                .iter()
+                .find(|(_, enclosure)| enclosure.posts.contains(&a) && enclosure.posts.contains(&b))
+                .map(|(entity, _)| entity);
+
+            needed.push((StaffTask::Repair { fence }, position, enclosure));
+        }
+
+        for (facility, state, transform) in self.facilities.iter() {
+            let Some(data) = self.facility_data.get(&state.data) else { continue };
+            let (Some(stock), Some(max_stock)) = (state.stock, data.stock) else { continue };
+
+            if (stock as f32) < max_stock as f32 * RESTOCK_THRESHOLD {
+                let position = transform.translation.xz();
+                needed.push((StaffTask::Restock { facility }, position, None));
+            }
+        }
+
+        for (litter, mess, transform) in self.litter.iter() {
+            let task = match mess.kind {
+                LitterKind::Trash => StaffTask::Sweep { litter },
+                LitterKind::Waste => StaffTask::MuckOut { litter },
+            };
+            needed.push((task, transform.translation.xz(), mess.enclosure));
+        }
+
+        for (bin, prop, contents, transform) in self.bins.iter() {
+            let Some(data) = self.prop_data.get(&prop.data) else { continue };
+            let PropFeature::Bin { capacity } = data.feature else { continue };
+
+            if contents.amount as f32 >= capacity as f32 * EMPTY_THRESHOLD {
+                let position = transform.translation.xz();
+                needed.push((
+                    StaffTask::EmptyBin { bin },
+                    position,
+                    enclosure_at(position),
+                ));
+            }
+        }
+
+        needed
+    }
+}
+
+/// Periodically looks over the zoo for work that needs doing, keeping the task queue up to date
+fn post_tasks(
+    clock: Res<ZooClock>,
+    sources: TaskSources,
+    mut tasks: ResMut<StaffTasks>,
+    staff: Query<(), With<Staff>>,
+    mut since_check: Local<f32>,
+) {
+    *since_check += clock.delta_seconds();
+    if *since_check < TASK_INTERVAL {
+        return;
+    }
+    let elapsed = std::mem::take(&mut *since_check);
+
+    let needed = sources.needed();
+
+    // drop tasks that no longer need doing, and open up those held by staff that have since been dismissed
+    tasks
+        .tasks
+        .retain(|pending| needed.iter().any(|&(task, ..)| task == pending.task));
+
+    for pending in tasks.tasks.iter_mut() {
+        if pending
+            .claimed_by
+            .is_some_and(|member| staff.get(member).is_err())
+        {
+            pending.claimed_by = None;
+        }
+
+        if pending.claimed_by.is_none() {
+            pending.waiting += elapsed;
+        }
+    }
+
+    for (task, position, enclosure) in needed {
+        match tasks.tasks.iter_mut().find(|pending| pending.task == task) {
+            // tasks can move, such as escaped animals running around
+            Some(pending) => pending.position = position,
+
+            None => tasks.tasks.push(PendingTask {
+                task,
+                position,
+                enclosure,
+                claimed_by: None,
+                waiting: 0.0,
+            }),
+        }
+    }
+}
+
+/// Everything around the zoo that finished tasks have an effect on
+#[derive(SystemParam)]
+pub(super) struct TaskTargets<'w, 's> {
+    facility_data: Res<'w, Assets<FacilityData>>,
+    litter: Query<'w, 's, (), With<Litter>>,
+    bins: Query<'w, 's, &'static mut BinContents>,
+    food: Query<'w, 's, &'static mut FoodSupply>,
+    fences: Query<'w, 's, &'static mut FenceCondition>,
+    facilities: Query<'w, 's, &'static mut Facility>,
+}
+
+/// Applies the results of each task that staff have finished
+pub(super) fn on_task_completed(
+    mut commands: Commands,
+    mut targets: TaskTargets,
+
+    mut completions: EventReader<TaskCompleted>,
+    mut recaptures: EventWriter<RecaptureAnimal>,
+    mut treatments: EventWriter<TreatAnimal>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    for completion in completions.iter() {
+        match completion.task {
+            StaffTask::Feed { prop } => {
+                let Ok(mut supply) = targets.food.get_mut(prop) else { continue };
+                supply.remaining = 1.0;
+                balance_changes.send(ZooBalanceChange {
+                    amount: -FEED_COST,
+                    category: LedgerCategory::Supplies,
+                });
+            }
+
+            StaffTask::Recapture { animal } => recaptures.send(RecaptureAnimal { animal }),
+
+            StaffTask::Treat { animal } => treatments.send(TreatAnimal { animal }),
+
+            StaffTask::Repair { fence } => {
+                let Ok(mut condition) = targets.fences.get_mut(fence) else { continue };
+                condition.condition = 1.0;
+                balance_changes.send(ZooBalanceChange {
+                    amount: -REPAIR_COST,
+                    category: LedgerCategory::Supplies,
+                });
+            }
+
+            StaffTask::Restock { facility } => {
+                let Ok(mut facility) = targets.facilities.get_mut(facility) else { continue };
+                let Some(data) = targets.facility_data.get(&facility.data) else { continue };
+
+                balance_changes.send(ZooBalanceChange {
+                    amount: -facility.restock(data),
+                    category: LedgerCategory::Supplies,
+                });
+            }
+
+            StaffTask::Sweep { litter: mess } | StaffTask::MuckOut { litter: mess } => {
+                if targets.litter.contains(mess) {
+                    commands.entity(mess).despawn_recursive();
+                }
+            }
+
+            StaffTask::EmptyBin { bin } => {
+                let Ok(mut contents) = targets.bins.get_mut(bin) else { continue };
+                contents.amount = 0;
+            }
+        }
+    }
+}
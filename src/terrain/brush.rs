@@ -0,0 +1,227 @@
+use super::{
+    heightmap::CELL_SIZE, paint, sculpt, sculpt::SculptMode, surface::SurfaceType,
+    water::WaterTool, Terrain, TerrainModified,
+};
+use crate::{
+    camera::CursorRaycast,
+    finance::LedgerCategory,
+    land::Land,
+    placement::{Preview, PreviewData},
+    ui::UiDisplay,
+    zoo::ZooBalanceChange,
+    CurrencyFormat,
+};
+use bevy::{ecs::system::SystemParam, math::Vec3Swizzles, prelude::*};
+
+pub struct TerrainBrushPlugin;
+impl Plugin for TerrainBrushPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TerrainBrushes>()
+            .add_systems(Update, (handle_brush_resize, draw_brush_outline));
+    }
+}
+
+// constants for brush sizing
+const DEFAULT_RADIUS: f32 = 3.0;
+const MIN_RADIUS: f32 = 1.0;
+const MAX_RADIUS: f32 = 10.0;
+const RADIUS_STEP: f32 = 0.5;
+
+/// Different tools that a terrain brush can apply
+#[derive(Clone, Copy, PartialEq)]
+pub enum BrushTool {
+    /// Changes the heights of the terrain
+    Sculpt(SculptMode),
+    /// Changes the surface type of the terrain
+    Paint(SurfaceType),
+    /// Digs or drains water bodies in the terrain
+    Water(WaterTool),
+}
+
+/// Brush that can be selected from the ui to modify the terrain
+#[derive(Clone)]
+pub struct TerrainBrush {
+    pub tool: BrushTool,
+    pub icon: Handle<Image>,
+}
+
+impl UiDisplay for TerrainBrush {
+    fn name(&self) -> String {
+        match self.tool {
+            BrushTool::Sculpt(SculptMode::Raise) => "Raise Terrain".into(),
+            BrushTool::Sculpt(SculptMode::Lower) => "Lower Terrain".into(),
+            BrushTool::Sculpt(SculptMode::Flatten) => "Flatten Terrain".into(),
+            BrushTool::Sculpt(SculptMode::Smooth) => "Smooth Terrain".into(),
+            BrushTool::Paint(surface) => format!("Paint {}", surface.name()),
+            BrushTool::Water(tool) => tool.name().into(),
+        }
+    }
+
+    fn image(&self) -> UiImage {
+        self.icon.clone().into()
+    }
+
+    fn text(&self) -> String {
+        match self.tool {
+            BrushTool::Sculpt(_) | BrushTool::Water(_) => {
+                format!("{}/m³", sculpt::COST_PER_VOLUME.comma_separated())
+            }
+            BrushTool::Paint(_) => format!("{}/m²", paint::COST_PER_AREA.comma_separated()),
+        }
+    }
+}
+
+impl PreviewData for TerrainBrush {
+    fn spawn_preview(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpatialBundle::default(),
+            BrushPreview {
+                tool: self.tool,
+                radius: DEFAULT_RADIUS,
+            },
+            Preview { cost: 0.0 },
+        ));
+    }
+}
+
+/// All terrain brushes that can be selected from the ui
+#[derive(Resource)]
+pub struct TerrainBrushes {
+    pub brushes: Vec<TerrainBrush>,
+}
+
+impl FromWorld for TerrainBrushes {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        let sculpt_tools = [
+            SculptMode::Raise,
+            SculptMode::Lower,
+            SculptMode::Flatten,
+            SculptMode::Smooth,
+        ]
+        .map(BrushTool::Sculpt);
+        let paint_tools = SurfaceType::ALL.map(BrushTool::Paint);
+        let water_tools = [
+            WaterTool::Pond,
+            WaterTool::River,
+            WaterTool::Pool,
+            WaterTool::Drain,
+        ]
+        .map(BrushTool::Water);
+
+        let brushes = sculpt_tools
+            .into_iter()
+            .chain(paint_tools)
+            .chain(water_tools)
+            .map(|tool| TerrainBrush {
+                tool,
+                icon: asset_server.load("test.png"),
+            })
+            .collect();
+
+        Self { brushes }
+    }
+}
+
+/// Component for the currently previewed brush
+#[derive(Component)]
+pub(super) struct BrushPreview {
+    pub tool: BrushTool,
+    pub radius: f32,
+}
+
+impl BrushPreview {
+    /// Strength of the brush at some distance from its center, falling off smoothly from 1 to 0 at its edge
+    pub fn falloff(&self, distance: f32) -> f32 {
+        (1.0 - distance / self.radius).clamp(0.0, 1.0).powi(2)
+    }
+}
+
+/// System parameter for brushes that change the heights of the terrain, charging the zoo for each change
+#[derive(SystemParam)]
+pub(super) struct TerrainEdit<'w> {
+    pub terrain: ResMut<'w, Terrain>,
+    pub land: Res<'w, Land>,
+    balance_changes: EventWriter<'w, ZooBalanceChange>,
+    modifications: EventWriter<'w, TerrainModified>,
+}
+
+impl TerrainEdit<'_> {
+    /// Return the vertices within the brush that are on land owned by the zoo, and so can be modified
+    pub fn owned_vertices(&self, center: Vec2, radius: f32) -> Vec<UVec2> {
+        self.terrain
+            .vertices_within(center, radius)
+            .filter(|&vertex| {
+                self.land
+                    .is_owned(self.terrain.vertex_position(vertex).xz())
+            })
+            .collect()
+    }
+
+    /// Return the cells within the brush that are on land owned by the zoo, and so can be modified
+    pub fn owned_cells(&self, center: Vec2, radius: f32) -> Vec<UVec2> {
+        self.terrain
+            .cells_within(center, radius)
+            .filter(|&cell| self.land.is_owned(self.terrain.cell_center(cell)))
+            .collect()
+    }
+
+    /// Sets the new heights of vertices within the brush, charging the zoo for the volume of terrain moved
+    pub fn set_heights(&mut self, heights: Vec<(UVec2, f32)>, center: Vec2, radius: f32) {
+        let volume = heights
+            .into_iter()
+            .map(|(vertex, height)| {
+                self.terrain.set_height(vertex, height).abs() * CELL_SIZE * CELL_SIZE
+            })
+            .sum::<f32>();
+
+        if volume > 0.0 {
+            self.balance_changes.send(ZooBalanceChange {
+                amount: -volume * sculpt::COST_PER_VOLUME,
+                category: LedgerCategory::Construction,
+            });
+
+            self.modifications.send(TerrainModified {
+                area: Rect::from_center_size(center, Vec2::splat(radius * 2.0)),
+            });
+        }
+    }
+}
+
+/// Changes the brush's radius with the bracket keys
+fn handle_brush_resize(keys: Res<Input<KeyCode>>, mut brush: Query<&mut BrushPreview>) {
+    let Ok(mut brush) = brush.get_single_mut() else { return };
+
+    if keys.just_pressed(KeyCode::BracketLeft) {
+        brush.radius = (brush.radius - RADIUS_STEP).max(MIN_RADIUS);
+    }
+    if keys.just_pressed(KeyCode::BracketRight) {
+        brush.radius = (brush.radius + RADIUS_STEP).min(MAX_RADIUS);
+    }
+}
+
+/// Draws the brush's area of effect along the terrain's surface
+fn draw_brush_outline(
+    cursor: CursorRaycast,
+    terrain: Res<Terrain>,
+    brush: Query<&BrushPreview>,
+    mut gizmos: Gizmos,
+) {
+    const SEGMENTS: usize = 48;
+
+    let Ok(brush) = brush.get_single() else { return };
+    let Some(center) = cursor.ground_point() else { return };
+
+    let outline = (0..=SEGMENTS).filter_map(|i| {
+        let angle = i as f32 / SEGMENTS as f32 * std::f32::consts::TAU;
+        let point = center.xz() + Vec2::from_angle(angle) * brush.radius;
+
+        // lift slightly to avoid clipping into the terrain
+        terrain
+            .height_at(point)
+            .map(|height| Vec3::new(point.x, height + 0.05, point.y))
+    });
+
+    gizmos.linestrip(outline, Color::WHITE);
+}
@@ -0,0 +1,240 @@
+use super::{
+    heightmap::{CELL_SIZE, CHUNK_CELLS, TERRAIN_CHUNKS},
+    Terrain,
+};
+use crate::objects::utility::CollisionLayer;
+use bevy::{
+    pbr::NotShadowCaster,
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+use bevy_rapier3d::prelude::*;
+
+/// Number of height samples along each side of a chunk
+const CHUNK_VERTICES: usize = CHUNK_CELLS + 1;
+
+/// Component for a single chunk of the terrain, which renders and collides with its section of the heightmap
+#[derive(Component)]
+pub struct TerrainChunk {
+    pub coordinate: UVec2,
+    /// Entity rendering and colliding with the water surfaces within this chunk
+    pub water: Entity,
+}
+
+/// Marker component for the water surface of a chunk
+#[derive(Component)]
+pub struct WaterSurface;
+
+/// Spawns in an empty entity for every terrain chunk, their meshes and colliders are built once they are dirty
+pub fn spawn_terrain(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // surface colors are blended into each chunk's vertex colors
+    let material = materials.add(Color::WHITE.into());
+    let water_material = materials.add(StandardMaterial {
+        base_color: Color::rgba(0.15, 0.4, 0.6, 0.7),
+        alpha_mode: AlphaMode::Blend,
+        perceptual_roughness: 0.1,
+        ..default()
+    });
+
+    commands
+        .spawn((SpatialBundle::default(), Name::new("Terrain")))
+        .with_children(|parent| {
+            for x in 0..TERRAIN_CHUNKS as u32 {
+                for z in 0..TERRAIN_CHUNKS as u32 {
+                    let coordinate = UVec2::new(x, z);
+                    let transform = Transform::from_translation(Terrain::chunk_center(coordinate));
+
+                    let water = parent
+                        .spawn((
+                            PbrBundle {
+                                mesh: meshes.add(Mesh::new(PrimitiveTopology::TriangleList)),
+                                material: water_material.clone(),
+                                transform,
+                                ..default()
+                            },
+                            WaterSurface,
+                            NotShadowCaster,
+                        ))
+                        .id();
+
+                    parent.spawn((
+                        PbrBundle {
+                            mesh: meshes.add(Mesh::new(PrimitiveTopology::TriangleList)),
+                            material: material.clone(),
+                            transform,
+                            ..default()
+                        },
+                        TerrainChunk { coordinate, water },
+                    ));
+                }
+            }
+        });
+}
+
+/// Rebuilds the mesh and collider of any chunks that were marked dirty by changes to the heightmap
+pub fn rebuild_dirty_chunks(
+    mut commands: Commands,
+    mut terrain: ResMut<Terrain>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    chunks: Query<(Entity, &TerrainChunk, &Handle<Mesh>)>,
+    water_surfaces: Query<&Handle<Mesh>, With<WaterSurface>>,
+) {
+    if !terrain.is_dirty() {
+        return;
+    }
+
+    let dirty_chunks = terrain.take_dirty_chunks();
+
+    for (entity, chunk, mesh_handle) in chunks.iter() {
+        if !dirty_chunks.contains(&chunk.coordinate) {
+            continue;
+        }
+
+        if let Some(mesh) = meshes.get_mut(mesh_handle) {
+            *mesh = chunk_mesh(&terrain, chunk.coordinate);
+        }
+
+        commands.entity(entity).insert((
+            RigidBody::Fixed,
+            chunk_collider(&terrain, chunk.coordinate),
+            CollisionGroups::new(CollisionLayer::Ground.into(), Group::ALL),
+        ));
+
+        // rebuild water surface, which only has a collider if there is any water in the chunk
+        let (water_mesh, water_collider) = water_mesh(&terrain, chunk.coordinate);
+
+        if let Some(mesh) = water_surfaces
+            .get(chunk.water)
+            .ok()
+            .and_then(|handle| meshes.get_mut(handle))
+        {
+            *mesh = water_mesh;
+        }
+
+        match water_collider {
+            Some(collider) => commands.entity(chunk.water).insert((
+                RigidBody::Fixed,
+                collider,
+                CollisionGroups::new(CollisionLayer::Water.into(), Group::ALL),
+            )),
+            None => commands
+                .entity(chunk.water)
+                .remove::<(RigidBody, Collider, CollisionGroups)>(),
+        };
+    }
+}
+
+/// Iterates over all vertices of a chunk, relative to the chunk's origin, in column-major order
+fn chunk_vertices() -> impl Iterator<Item = UVec2> {
+    (0..CHUNK_VERTICES as u32)
+        .flat_map(|x| (0..CHUNK_VERTICES as u32).map(move |z| UVec2::new(x, z)))
+}
+
+/// Builds the render mesh of a chunk, with positions relative to the chunk's center
+fn chunk_mesh(terrain: &Terrain, chunk: UVec2) -> Mesh {
+    let origin = Terrain::chunk_origin(chunk);
+    let center = Terrain::chunk_center(chunk);
+
+    let mut positions = Vec::with_capacity(CHUNK_VERTICES * CHUNK_VERTICES);
+    let mut normals = Vec::with_capacity(CHUNK_VERTICES * CHUNK_VERTICES);
+    let mut uvs = Vec::with_capacity(CHUNK_VERTICES * CHUNK_VERTICES);
+    let mut colors = Vec::with_capacity(CHUNK_VERTICES * CHUNK_VERTICES);
+
+    for local in chunk_vertices() {
+        let vertex = origin + local;
+
+        positions.push((terrain.vertex_position(vertex) - center).to_array());
+        normals.push(terrain.normal(vertex).to_array());
+        uvs.push((local.as_vec2() * CELL_SIZE).to_array());
+        colors.push(terrain.splat_color(vertex).as_linear_rgba_f32());
+    }
+
+    // triangulate each cell the same way as the heightfield collider
+    let index = |x: usize, z: usize| (x * CHUNK_VERTICES + z) as u32;
+    let mut indices = Vec::with_capacity(CHUNK_CELLS * CHUNK_CELLS * 6);
+
+    for x in 0..CHUNK_CELLS {
+        for z in 0..CHUNK_CELLS {
+            indices.extend([index(x, z), index(x, z + 1), index(x + 1, z)]);
+            indices.extend([index(x, z + 1), index(x + 1, z + 1), index(x + 1, z)]);
+        }
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh.set_indices(Some(Indices::U32(indices)));
+
+    mesh
+}
+
+/// Builds the render mesh and collider of the water surfaces within a chunk, with positions relative to the chunk's center
+///
+/// A flat quad is placed over every cell with water, the collider is `None` if there are no cells with water
+fn water_mesh(terrain: &Terrain, chunk: UVec2) -> (Mesh, Option<Collider>) {
+    let origin = Terrain::chunk_origin(chunk);
+    let center = Terrain::chunk_center(chunk);
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+
+    for x in 0..CHUNK_CELLS as u32 {
+        for z in 0..CHUNK_CELLS as u32 {
+            let cell = origin + UVec2::new(x, z);
+            let Some(level) = terrain.water_level(cell) else { continue };
+
+            let start = positions.len() as u32;
+            for corner in [UVec2::ZERO, UVec2::Y, UVec2::X, UVec2::ONE] {
+                let position = terrain.vertex_position(cell + corner);
+                positions.push(Vec3::new(position.x, level, position.z) - center);
+            }
+
+            indices.push([start, start + 1, start + 2]);
+            indices.push([start + 1, start + 3, start + 2]);
+        }
+    }
+
+    let collider = match indices.is_empty() {
+        true => None,
+        false => Some(Collider::trimesh(positions.clone(), indices.clone())),
+    };
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_NORMAL,
+        vec![[0.0, 1.0, 0.0]; positions.len()],
+    );
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_POSITION,
+        positions
+            .into_iter()
+            .map(|p| p.to_array())
+            .collect::<Vec<_>>(),
+    );
+    mesh.set_indices(Some(Indices::U32(indices.concat())));
+
+    (mesh, collider)
+}
+
+/// Builds the heightfield collider of a chunk, centered on the chunk's center
+fn chunk_collider(terrain: &Terrain, chunk: UVec2) -> Collider {
+    let origin = Terrain::chunk_origin(chunk);
+
+    // heightfield rows run along the z-axis and columns along the x-axis, stored in column-major order
+    let heights = chunk_vertices()
+        .map(|local| terrain.height(origin + local))
+        .collect();
+
+    Collider::heightfield(
+        heights,
+        CHUNK_VERTICES,
+        CHUNK_VERTICES,
+        Vec3::new(Terrain::CHUNK_SIZE, 1.0, Terrain::CHUNK_SIZE),
+    )
+}
@@ -0,0 +1,299 @@
+use super::surface::SurfaceType;
+use bevy::{math::Vec3Swizzles, prelude::*};
+use std::collections::HashSet;
+
+/// Size of a single terrain cell in meters
+pub const CELL_SIZE: f32 = 1.0;
+/// Number of cells along each side of a chunk
+pub const CHUNK_CELLS: usize = 16;
+/// Number of chunks along each side of the terrain
+pub const TERRAIN_CHUNKS: usize = 8;
+
+/// Number of cells along each side of the terrain
+const TERRAIN_CELLS: usize = CHUNK_CELLS * TERRAIN_CHUNKS;
+/// Number of height samples along each side of the terrain, there is one more than the number of cells
+const TERRAIN_VERTICES: usize = TERRAIN_CELLS + 1;
+
+/// Lowest height the terrain can be sculpted to
+const MIN_HEIGHT: f32 = -10.0;
+/// Highest height the terrain can be sculpted to
+const MAX_HEIGHT: f32 = 20.0;
+
+/// Heightmap, surface types and water for the entire terrain, centered on the world origin
+///
+/// Heights are stored per vertex, where vertex `(x, z)` is the corner with the lowest coordinates of cell `(x, z)`
+#[derive(Resource)]
+pub struct Terrain {
+    heights: Vec<f32>,
+    pub(super) surfaces: Vec<SurfaceType>,
+    pub(super) water_levels: Vec<Option<f32>>,
+    /// Chunks whose mesh and collider no longer match the heightmap
+    dirty_chunks: HashSet<UVec2>,
+}
+
+impl Default for Terrain {
+    fn default() -> Self {
+        // start completely flat, with every chunk needing to be built
+        let chunks = (0..TERRAIN_CHUNKS as u32)
+            .flat_map(|x| (0..TERRAIN_CHUNKS as u32).map(move |z| UVec2::new(x, z)));
+
+        Self {
+            heights: vec![0.0; TERRAIN_VERTICES * TERRAIN_VERTICES],
+            surfaces: vec![SurfaceType::default(); TERRAIN_CELLS * TERRAIN_CELLS],
+            water_levels: vec![None; TERRAIN_CELLS * TERRAIN_CELLS],
+            dirty_chunks: chunks.collect(),
+        }
+    }
+}
+
+impl Terrain {
+    /// Number of cells along each side of the terrain
+    pub const CELLS: usize = TERRAIN_CELLS;
+
+    /// Side length of the entire terrain in meters
+    pub const SIZE: f32 = TERRAIN_CELLS as f32 * CELL_SIZE;
+
+    /// Side length of a single chunk in meters
+    pub const CHUNK_SIZE: f32 = CHUNK_CELLS as f32 * CELL_SIZE;
+
+    /// Area covered by the terrain on the XZ-plane
+    pub fn bounds(&self) -> Rect {
+        Rect::from_center_size(Vec2::ZERO, Vec2::splat(Self::SIZE))
+    }
+
+    /// Return the height stored at the given vertex
+    pub fn height(&self, vertex: UVec2) -> f32 {
+        self.heights[Self::index(vertex)]
+    }
+
+    /// Set the height of the given vertex, marking any affected chunks as dirty
+    ///
+    /// Returns the actual change in height after clamping to the allowed range
+    pub fn set_height(&mut self, vertex: UVec2, height: f32) -> f32 {
+        let index = Self::index(vertex);
+        let previous = self.heights[index];
+        self.heights[index] = height.clamp(MIN_HEIGHT, MAX_HEIGHT);
+
+        let change = self.heights[index] - previous;
+        if change != 0.0 {
+            self.mark_dirty(vertex);
+        }
+
+        change
+    }
+
+    /// Return the world position of the given vertex
+    pub fn vertex_position(&self, vertex: UVec2) -> Vec3 {
+        let position = vertex.as_vec2() * CELL_SIZE - Self::SIZE / 2.0;
+        Vec3::new(position.x, self.height(vertex), position.y)
+    }
+
+    /// Return the vertex nearest to the given point, clamped to the terrain's edges
+    pub fn nearest_vertex(&self, point: Vec2) -> UVec2 {
+        ((point + Self::SIZE / 2.0) / CELL_SIZE)
+            .round()
+            .clamp(Vec2::ZERO, Vec2::splat(TERRAIN_CELLS as f32))
+            .as_uvec2()
+    }
+
+    /// Return the height of the terrain's surface at the given point on the XZ-plane
+    ///
+    /// Heights are interpolated across the same triangles used by the chunk colliders
+    ///
+    /// Returns `None` if the point is outside of the terrain
+    pub fn height_at(&self, point: Vec2) -> Option<f32> {
+        if !self.bounds().contains(point) {
+            return None;
+        }
+
+        // find the cell containing the point and the point's offset within it
+        let grid = (point + Self::SIZE / 2.0) / CELL_SIZE;
+        let cell = grid
+            .floor()
+            .clamp(Vec2::ZERO, Vec2::splat(TERRAIN_CELLS as f32 - 1.0));
+        let offset = grid - cell;
+        let cell = cell.as_uvec2();
+
+        let h00 = self.height(cell);
+        let h10 = self.height(cell + UVec2::X);
+        let h01 = self.height(cell + UVec2::Y);
+        let h11 = self.height(cell + UVec2::ONE);
+
+        // each cell is split into two triangles along its diagonal
+        let height = match offset.x + offset.y <= 1.0 {
+            true => h00 + (h10 - h00) * offset.x + (h01 - h00) * offset.y,
+            false => h11 + (h01 - h11) * (1.0 - offset.x) + (h10 - h11) * (1.0 - offset.y),
+        };
+
+        Some(height)
+    }
+
+    /// Return the surface normal at the given vertex, estimated from its neighbors
+    pub fn normal(&self, vertex: UVec2) -> Vec3 {
+        let [left, right, down, up] = self.neighbors(vertex).map(|v| self.height(v));
+        Vec3::new(left - right, 2.0 * CELL_SIZE, down - up).normalize()
+    }
+
+    /// Return the average height of the 4 vertices neighboring the given vertex
+    pub fn neighbor_average(&self, vertex: UVec2) -> f32 {
+        self.neighbors(vertex)
+            .map(|v| self.height(v))
+            .iter()
+            .sum::<f32>()
+            / 4.0
+    }
+
+    /// Return all vertices within the given radius of a point on the XZ-plane
+    pub fn vertices_within(&self, center: Vec2, radius: f32) -> impl Iterator<Item = UVec2> + '_ {
+        let min = self.nearest_vertex(center - radius);
+        let max = self.nearest_vertex(center + radius);
+
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |z| UVec2::new(x, z)))
+            .filter(move |&v| self.vertex_position(v).xz().distance(center) <= radius)
+    }
+
+    /// Return the vertex at the corner of the given chunk with the lowest coordinates
+    pub fn chunk_origin(chunk: UVec2) -> UVec2 {
+        chunk * CHUNK_CELLS as u32
+    }
+
+    /// Return the world position of the center of the given chunk, at a height of 0
+    pub fn chunk_center(chunk: UVec2) -> Vec3 {
+        let center = (chunk.as_vec2() + 0.5) * Self::CHUNK_SIZE - Self::SIZE / 2.0;
+        Vec3::new(center.x, 0.0, center.y)
+    }
+
+    /// Returns true if any chunks need to be rebuilt
+    pub(super) fn is_dirty(&self) -> bool {
+        !self.dirty_chunks.is_empty()
+    }
+
+    /// Clears and returns the set of chunks that need to be rebuilt
+    pub(super) fn take_dirty_chunks(&mut self) -> HashSet<UVec2> {
+        std::mem::take(&mut self.dirty_chunks)
+    }
+
+    /// Marks all chunks whose mesh depends on the given vertex as dirty, including neighbors for their normals
+    pub(super) fn mark_dirty(&mut self, vertex: UVec2) {
+        let min =
+            UVec2::new(vertex.x.saturating_sub(1), vertex.y.saturating_sub(1)) / CHUNK_CELLS as u32;
+        let max = ((vertex + UVec2::ONE) / CHUNK_CELLS as u32)
+            .min(UVec2::splat(TERRAIN_CHUNKS as u32 - 1));
+
+        for x in min.x..=max.x {
+            for z in min.y..=max.y {
+                self.dirty_chunks.insert(UVec2::new(x, z));
+            }
+        }
+    }
+
+    /// Return the 4 vertices to the left, right, down and up of the given vertex, clamped to the terrain's edges
+    fn neighbors(&self, vertex: UVec2) -> [UVec2; 4] {
+        let max = TERRAIN_CELLS as u32;
+
+        [
+            UVec2::new(vertex.x.saturating_sub(1), vertex.y),
+            UVec2::new((vertex.x + 1).min(max), vertex.y),
+            UVec2::new(vertex.x, vertex.y.saturating_sub(1)),
+            UVec2::new(vertex.x, (vertex.y + 1).min(max)),
+        ]
+    }
+
+    fn index(vertex: UVec2) -> usize {
+        vertex.y as usize * TERRAIN_VERTICES + vertex.x as usize
+    }
+
+    pub(super) fn cell_index(cell: UVec2) -> usize {
+        cell.y as usize * TERRAIN_CELLS + cell.x as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Terrain with no chunks waiting to be rebuilt, so only new changes are marked dirty
+    fn clean_terrain() -> Terrain {
+        let mut terrain = Terrain::default();
+        terrain.take_dirty_chunks();
+        terrain
+    }
+
+    #[test]
+    fn heights_match_vertices_on_chunk_borders() {
+        let mut terrain = clean_terrain();
+        let border = CHUNK_CELLS as u32;
+        let far_edge = TERRAIN_CELLS as u32;
+
+        for vertex in [
+            UVec2::new(border, 3),
+            UVec2::new(border, border),
+            UVec2::ZERO,
+            UVec2::splat(far_edge),
+        ] {
+            terrain.set_height(vertex, 2.0);
+            let height = terrain.height_at(terrain.vertex_position(vertex).xz());
+            assert_eq!(height, Some(2.0), "height at vertex {vertex}");
+        }
+    }
+
+    #[test]
+    fn heights_are_continuous_across_chunk_borders() {
+        let mut terrain = clean_terrain();
+        let border = CHUNK_CELLS as u32;
+        for z in 0..=TERRAIN_CELLS as u32 {
+            terrain.set_height(UVec2::new(border, z), 4.0);
+        }
+
+        let point = terrain.vertex_position(UVec2::new(border, 5)).xz();
+        let before = terrain.height_at(point - Vec2::X * 0.001).unwrap();
+        let after = terrain.height_at(point + Vec2::X * 0.001).unwrap();
+        assert!((before - 4.0).abs() < 0.01 && (after - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn heights_outside_the_terrain_are_none() {
+        let terrain = clean_terrain();
+        let edge = Terrain::SIZE / 2.0;
+
+        assert_eq!(terrain.height_at(Vec2::new(edge + 0.01, 0.0)), None);
+        assert_eq!(terrain.height_at(Vec2::new(0.0, -edge - 0.01)), None);
+    }
+
+    #[test]
+    fn nearest_vertex_is_clamped_to_the_edges() {
+        let terrain = clean_terrain();
+        let far_edge = TERRAIN_CELLS as u32;
+
+        assert_eq!(terrain.nearest_vertex(Vec2::splat(-1000.0)), UVec2::ZERO);
+        assert_eq!(
+            terrain.nearest_vertex(Vec2::splat(1000.0)),
+            UVec2::splat(far_edge)
+        );
+        assert_eq!(
+            terrain.nearest_vertex(Vec2::new(0.4, -0.4)),
+            UVec2::splat(far_edge / 2)
+        );
+    }
+
+    #[test]
+    fn editing_a_border_vertex_dirties_both_chunks() {
+        let mut terrain = clean_terrain();
+        terrain.set_height(UVec2::new(CHUNK_CELLS as u32, 3), 1.0);
+
+        let dirty = terrain.take_dirty_chunks();
+        assert_eq!(dirty, HashSet::from([UVec2::new(0, 0), UVec2::new(1, 0)]));
+    }
+
+    #[test]
+    fn editing_a_corner_vertex_stays_within_the_terrain() {
+        let mut terrain = clean_terrain();
+        terrain.set_height(UVec2::ZERO, 1.0);
+        assert_eq!(terrain.take_dirty_chunks(), HashSet::from([UVec2::ZERO]));
+
+        let last_chunk = UVec2::splat(TERRAIN_CHUNKS as u32 - 1);
+        terrain.set_height(UVec2::splat(TERRAIN_CELLS as u32), 1.0);
+        assert_eq!(terrain.take_dirty_chunks(), HashSet::from([last_chunk]));
+    }
+}
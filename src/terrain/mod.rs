@@ -0,0 +1,65 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+mod brush;
+mod chunk;
+mod heightmap;
+mod paint;
+mod sculpt;
+mod surface;
+mod water;
+
+pub use brush::TerrainBrushes;
+pub use heightmap::Terrain;
+pub use surface::{SurfaceCoverage, SurfaceType};
+pub use water::WaterMobility;
+
+pub struct TerrainPlugin;
+impl Plugin for TerrainPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Terrain>()
+            .add_event::<TerrainModified>()
+            .add_plugins((
+                brush::TerrainBrushPlugin,
+                sculpt::TerrainSculptPlugin,
+                paint::TerrainPaintPlugin,
+                water::TerrainWaterPlugin,
+            ))
+            .add_systems(Startup, chunk::spawn_terrain)
+            .add_systems(Update, seat_objects)
+            // chunks are rebuilt after all of this frame's sculpting has been applied
+            .add_systems(PostUpdate, chunk::rebuild_dirty_chunks);
+    }
+}
+
+/// Marker component for objects that should stay seated on the terrain's surface when it is sculpted
+#[derive(Component)]
+pub struct SeatOnTerrain;
+
+/// Event sent whenever the terrain's heights change within some area on the XZ-plane
+#[derive(Event)]
+pub struct TerrainModified {
+    pub area: Rect,
+}
+
+/// Moves seated objects within modified areas of the terrain back onto its surface
+fn seat_objects(
+    terrain: Res<Terrain>,
+    mut modifications: EventReader<TerrainModified>,
+    mut seated: Query<&mut Transform, With<SeatOnTerrain>>,
+) {
+    for modification in modifications.iter() {
+        for mut transform in seated.iter_mut() {
+            let position = transform.translation.xz();
+            if !modification.area.contains(position) {
+                continue;
+            }
+
+            // only write to the transform when needed, to avoid triggering change detection
+            if let Some(height) = terrain.height_at(position) {
+                if transform.translation.y != height {
+                    transform.translation.y = height;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::{
+    brush::{BrushPreview, BrushTool},
+    heightmap::CELL_SIZE,
+    Terrain,
+};
+use crate::{
+    camera::CursorRaycast, finance::LedgerCategory, land::Land, zoo::ZooBalanceChange, Currency,
+};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct TerrainPaintPlugin;
+impl Plugin for TerrainPaintPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_paint);
+    }
+}
+
+/// Cost of each square meter of terrain that has its surface changed
+pub const COST_PER_AREA: Currency = 1.0;
+
+/// Paints the surface type of the terrain under the cursor while the left mouse button is held
+fn apply_paint(
+    mouse: Res<Input<MouseButton>>,
+    cursor: CursorRaycast,
+    mut terrain: ResMut<Terrain>,
+    land: Res<Land>,
+    brush: Query<&BrushPreview>,
+    mut balance_changes: EventWriter<ZooBalanceChange>,
+) {
+    let Ok(brush) = brush.get_single() else { return };
+    let BrushTool::Paint(surface) = brush.tool else { return };
+
+    if !mouse.pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(center) = cursor.ground_point() else { return };
+
+    let cells = terrain
+        .cells_within(center.xz(), brush.radius)
+        // only land owned by the zoo can be painted
+        .filter(|&cell| land.is_owned(terrain.cell_center(cell)))
+        .collect::<Vec<_>>();
+
+    // only charge for cells that actually changed
+    let painted = cells
+        .into_iter()
+        .filter(|&cell| terrain.set_surface(cell, surface))
+        .count();
+
+    if painted > 0 {
+        balance_changes.send(ZooBalanceChange {
+            amount: -(painted as f32) * CELL_SIZE * CELL_SIZE * COST_PER_AREA,
+            category: LedgerCategory::Construction,
+        });
+    }
+}
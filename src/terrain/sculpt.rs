@@ -0,0 +1,80 @@
+use super::brush::{BrushPreview, BrushTool, TerrainEdit};
+use crate::{camera::CursorRaycast, Currency};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct TerrainSculptPlugin;
+impl Plugin for TerrainSculptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_sculpt);
+    }
+}
+
+// constants for sculpting
+/// Cost of each cubic meter of terrain that is raised or lowered
+pub const COST_PER_VOLUME: Currency = 5.0;
+/// Height change per second at the center of the raise and lower brushes
+const SCULPT_SPEED: f32 = 2.0;
+/// Fraction of the remaining difference covered per second by the flatten and smooth brushes
+const BLEND_SPEED: f32 = 4.0;
+
+/// Different ways that a brush can sculpt the terrain
+#[derive(Clone, Copy, PartialEq)]
+pub enum SculptMode {
+    /// Pushes the terrain upwards
+    Raise,
+    /// Pushes the terrain downwards
+    Lower,
+    /// Levels the terrain to the height where the stroke began
+    Flatten,
+    /// Averages out bumps in the terrain
+    Smooth,
+}
+
+/// Sculpts the terrain under the cursor while the left mouse button is held, charging for the volume moved
+fn apply_sculpt(
+    mouse: Res<Input<MouseButton>>,
+    time: Res<Time>,
+    cursor: CursorRaycast,
+    mut edit: TerrainEdit,
+    brush: Query<&BrushPreview>,
+    // height that the flatten brush is leveling to, set at the start of each stroke
+    mut flatten_height: Local<Option<f32>>,
+) {
+    let Ok(brush) = brush.get_single() else { return };
+    let BrushTool::Sculpt(mode) = brush.tool else { return };
+
+    // strokes end when the mouse is released
+    if !mouse.pressed(MouseButton::Left) {
+        *flatten_height = None;
+        return;
+    }
+
+    let Some(center) = cursor.ground_point() else { return };
+    let flatten_height = *flatten_height.get_or_insert(center.y);
+
+    // calculate every new height before applying any, so smoothing only reads unmodified neighbors
+    let delta = time.delta_seconds();
+    let terrain = &edit.terrain;
+    let new_heights = edit
+        .owned_vertices(center.xz(), brush.radius)
+        .into_iter()
+        .map(|vertex| {
+            let distance = terrain.vertex_position(vertex).xz().distance(center.xz());
+            let falloff = brush.falloff(distance);
+
+            let height = terrain.height(vertex);
+            let blend = (BLEND_SPEED * falloff * delta).min(1.0);
+
+            let new_height = match mode {
+                SculptMode::Raise => height + SCULPT_SPEED * falloff * delta,
+                SculptMode::Lower => height - SCULPT_SPEED * falloff * delta,
+                SculptMode::Flatten => height + (flatten_height - height) * blend,
+                SculptMode::Smooth => height + (terrain.neighbor_average(vertex) - height) * blend,
+            };
+
+            (vertex, new_height)
+        })
+        .collect::<Vec<_>>();
+
+    edit.set_heights(new_heights, center.xz(), brush.radius);
+}
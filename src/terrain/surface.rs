@@ -0,0 +1,198 @@
+use super::{heightmap::CELL_SIZE, Terrain};
+use crate::polygon::Polygon;
+use bevy::prelude::*;
+
+/// Ground type covering a single terrain cell
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SurfaceType {
+    #[default]
+    Grass,
+    Sand,
+    Dirt,
+    Snow,
+    Rock,
+}
+
+impl SurfaceType {
+    /// Every surface type, ordered by their index
+    pub const ALL: [SurfaceType; 5] = [
+        SurfaceType::Grass,
+        SurfaceType::Sand,
+        SurfaceType::Dirt,
+        SurfaceType::Snow,
+        SurfaceType::Rock,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SurfaceType::Grass => "Grass",
+            SurfaceType::Sand => "Sand",
+            SurfaceType::Dirt => "Dirt",
+            SurfaceType::Snow => "Snow",
+            SurfaceType::Rock => "Rock",
+        }
+    }
+
+    /// Color the surface is rendered with
+    pub fn color(&self) -> Color {
+        match self {
+            SurfaceType::Grass => Color::DARK_GREEN,
+            SurfaceType::Sand => Color::rgb(0.86, 0.78, 0.55),
+            SurfaceType::Dirt => Color::rgb(0.4, 0.29, 0.18),
+            SurfaceType::Snow => Color::rgb(0.93, 0.95, 0.97),
+            SurfaceType::Rock => Color::rgb(0.45, 0.45, 0.47),
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Fraction of some area covered by each surface type
+#[derive(Clone, Debug, Default)]
+pub struct SurfaceCoverage {
+    fractions: [f32; SurfaceType::ALL.len()],
+}
+
+impl SurfaceCoverage {
+    /// Return the fraction of the area covered by the given surface, from 0 to 1
+    pub fn fraction(&self, surface: SurfaceType) -> f32 {
+        self.fractions[surface.index()]
+    }
+}
+
+impl Terrain {
+    /// Return the surface type of the given cell
+    pub fn surface(&self, cell: UVec2) -> SurfaceType {
+        self.surfaces[Self::cell_index(cell)]
+    }
+
+    /// Set the surface type of the given cell, marking any affected chunks as dirty
+    ///
+    /// Returns true if the cell's surface was changed
+    pub fn set_surface(&mut self, cell: UVec2, surface: SurfaceType) -> bool {
+        let index = Self::cell_index(cell);
+        if self.surfaces[index] == surface {
+            return false;
+        }
+
+        self.surfaces[index] = surface;
+
+        // the colors of all 4 corners of the cell depend on its surface
+        for corner in [UVec2::ZERO, UVec2::X, UVec2::Y, UVec2::ONE] {
+            self.mark_dirty(cell + corner);
+        }
+
+        true
+    }
+
+    /// Return the surface type at the given point on the XZ-plane
+    ///
+    /// Returns `None` if the point is outside of the terrain
+    pub fn surface_at(&self, point: Vec2) -> Option<SurfaceType> {
+        self.cell_at(point).map(|cell| self.surface(cell))
+    }
+
+    /// Return the fraction of each surface type covering the area within the polygon
+    ///
+    /// Cells are counted as within the polygon if their center is
+    pub fn surface_coverage(&self, polygon: &Polygon) -> SurfaceCoverage {
+        let bounds = polygon.bounds();
+        let mut coverage = SurfaceCoverage::default();
+        let mut cells = 0;
+
+        for cell in self.cells_within_rect(bounds) {
+            if polygon.contains(self.cell_center(cell)) {
+                coverage.fractions[self.surface(cell).index()] += 1.0;
+                cells += 1;
+            }
+        }
+
+        if cells > 0 {
+            for fraction in coverage.fractions.iter_mut() {
+                *fraction /= cells as f32;
+            }
+        }
+
+        coverage
+    }
+
+    /// Return the blend weight of each surface type at the given vertex, from the cells surrounding it
+    pub fn splat_weights(&self, vertex: UVec2) -> [f32; SurfaceType::ALL.len()] {
+        let mut weights = [0.0; SurfaceType::ALL.len()];
+
+        // cells sharing this vertex, which are missing on the terrain's edges
+        let cells = [IVec2::ZERO, IVec2::NEG_X, IVec2::NEG_Y, IVec2::NEG_ONE]
+            .map(|offset| vertex.as_ivec2() + offset)
+            .into_iter()
+            .filter(|cell| {
+                cell.cmpge(IVec2::ZERO).all() && cell.cmplt(IVec2::splat(Self::CELLS as i32)).all()
+            })
+            .collect::<Vec<_>>();
+
+        for cell in cells.iter() {
+            weights[self.surface(cell.as_uvec2()).index()] += 1.0 / cells.len() as f32;
+        }
+
+        weights
+    }
+
+    /// Return the blended color of the surfaces at the given vertex
+    pub fn splat_color(&self, vertex: UVec2) -> Color {
+        let weights = self.splat_weights(vertex);
+
+        SurfaceType::ALL
+            .iter()
+            .zip(weights)
+            .fold(Color::NONE, |color, (surface, weight)| {
+                color + surface.color() * weight
+            })
+    }
+
+    /// Return the world position of the center of the given cell on the XZ-plane
+    pub fn cell_center(&self, cell: UVec2) -> Vec2 {
+        (cell.as_vec2() + 0.5) * CELL_SIZE - Self::SIZE / 2.0
+    }
+
+    /// Return the cell containing the given point, or `None` if it is outside of the terrain
+    pub fn cell_at(&self, point: Vec2) -> Option<UVec2> {
+        if !self.bounds().contains(point) {
+            return None;
+        }
+
+        let cell = ((point + Self::SIZE / 2.0) / CELL_SIZE)
+            .floor()
+            .min(Vec2::splat(Self::CELLS as f32 - 1.0));
+
+        Some(cell.as_uvec2())
+    }
+
+    /// Return all cells with centers within the given radius of a point on the XZ-plane
+    pub fn cells_within(&self, center: Vec2, radius: f32) -> impl Iterator<Item = UVec2> + '_ {
+        let area = Rect::from_center_size(center, Vec2::splat(radius * 2.0));
+
+        self.cells_within_rect(area)
+            .filter(move |&cell| self.cell_center(cell).distance(center) <= radius)
+    }
+
+    /// Return all cells overlapping the given rectangle on the XZ-plane
+    pub(super) fn cells_within_rect(&self, area: Rect) -> impl Iterator<Item = UVec2> {
+        let area = area.intersect(self.bounds());
+        let to_cell = |point: Vec2| {
+            ((point + Self::SIZE / 2.0) / CELL_SIZE)
+                .floor()
+                .clamp(Vec2::ZERO, Vec2::splat(Self::CELLS as f32 - 1.0))
+                .as_uvec2()
+        };
+
+        // an empty intersection has no cells
+        let (min, max) = (to_cell(area.min), to_cell(area.max));
+        let (min, max) = match area.is_empty() {
+            true => (UVec2::ONE, UVec2::ZERO),
+            false => (min, max),
+        };
+
+        (min.x..=max.x).flat_map(move |x| (min.y..=max.y).map(move |z| UVec2::new(x, z)))
+    }
+}
@@ -0,0 +1,228 @@
+use super::{
+    brush::{BrushPreview, BrushTool, TerrainEdit},
+    heightmap::CELL_SIZE,
+    surface::SurfaceType,
+    Terrain,
+};
+use crate::{camera::CursorRaycast, objects::utility::CollisionLayer, polygon::Polygon};
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+pub struct TerrainWaterPlugin;
+impl Plugin for TerrainWaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, apply_water);
+    }
+}
+
+/// Distance between the surrounding ground and the surface of newly dug water
+const SURFACE_OFFSET: f32 = 0.1;
+
+/// Different water bodies that can be dug into the terrain, or drained from it
+#[derive(Clone, Copy, PartialEq)]
+pub enum WaterTool {
+    /// Deep bowl-shaped water at a constant level
+    Pond,
+    /// Shallow channel that follows the ground's height along the stroke
+    River,
+    /// Flat-bottomed water lined with rock
+    Pool,
+    /// Removes water without changing the terrain
+    Drain,
+}
+
+impl WaterTool {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WaterTool::Pond => "Dig Pond",
+            WaterTool::River => "Dig River",
+            WaterTool::Pool => "Build Pool",
+            WaterTool::Drain => "Drain Water",
+        }
+    }
+
+    /// Depth at the center of the water body below its surface
+    fn depth(&self) -> f32 {
+        match self {
+            WaterTool::Pond => 1.5,
+            WaterTool::River => 0.8,
+            WaterTool::Pool => 2.0,
+            WaterTool::Drain => 0.0,
+        }
+    }
+
+    /// Fraction of the full depth dug at some distance from the brush's center, relative to its radius
+    fn profile(&self, relative_distance: f32) -> f32 {
+        match self {
+            WaterTool::Pool => 1.0,
+            _ => (1.0 - relative_distance.powi(2)).max(0.0).sqrt(),
+        }
+    }
+}
+
+/// How an agent is able to move through water
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WaterMobility {
+    /// Can only enter water up to the given depth in meters
+    Wading { max_depth: f32 },
+    /// Can move through water of any depth
+    Swimming,
+}
+
+impl Default for WaterMobility {
+    fn default() -> Self {
+        Self::Wading { max_depth: 0.3 }
+    }
+}
+
+/// Summary of the water within some area
+#[derive(Clone, Debug, Default)]
+pub struct WaterReport {
+    /// Area covered by water in square meters
+    pub area: f32,
+    /// Fraction of the total area covered by water, from 0 to 1
+    pub fraction: f32,
+    pub average_depth: f32,
+    pub max_depth: f32,
+}
+
+impl Terrain {
+    /// Return the height of the water's surface in the given cell, if it has any
+    pub fn water_level(&self, cell: UVec2) -> Option<f32> {
+        self.water_levels[Self::cell_index(cell)]
+    }
+
+    /// Set or remove the water in the given cell, marking any affected chunks as dirty
+    pub fn set_water_level(&mut self, cell: UVec2, level: Option<f32>) {
+        let index = Self::cell_index(cell);
+        if self.water_levels[index] == level {
+            return;
+        }
+
+        self.water_levels[index] = level;
+        self.mark_dirty(cell);
+    }
+
+    /// Return the depth of water at the given point on the XZ-plane, which is 0 on land
+    pub fn water_depth_at(&self, point: Vec2) -> f32 {
+        let Some(level) = self.cell_at(point).and_then(|cell| self.water_level(cell)) else {
+            return 0.0;
+        };
+        let Some(height) = self.height_at(point) else { return 0.0 };
+
+        (level - height).max(0.0)
+    }
+
+    /// Returns true if an agent with the given mobility is able to stand or swim at the given point
+    pub fn is_passable(&self, point: Vec2, mobility: WaterMobility) -> bool {
+        match mobility {
+            WaterMobility::Wading { max_depth } => self.water_depth_at(point) <= max_depth,
+            WaterMobility::Swimming => true,
+        }
+    }
+
+    /// Return a summary of the water within the polygon
+    ///
+    /// Cells are counted as within the polygon if their center is
+    pub fn water_report(&self, polygon: &Polygon) -> WaterReport {
+        let mut report = WaterReport::default();
+        let mut cells = 0;
+        let mut water_cells = 0;
+
+        for cell in self.cells_within_rect(polygon.bounds()) {
+            let center = self.cell_center(cell);
+            if !polygon.contains(center) {
+                continue;
+            }
+
+            cells += 1;
+
+            let depth = self.water_depth_at(center);
+            if depth > 0.0 {
+                water_cells += 1;
+                report.average_depth += depth;
+                report.max_depth = report.max_depth.max(depth);
+            }
+        }
+
+        report.area = water_cells as f32 * CELL_SIZE * CELL_SIZE;
+        if cells > 0 {
+            report.fraction = water_cells as f32 / cells as f32;
+        }
+        if water_cells > 0 {
+            report.average_depth /= water_cells as f32;
+        }
+
+        report
+    }
+}
+
+/// Digs and fills water bodies under the cursor while the left mouse button is held, charging for the volume dug
+fn apply_water(
+    mouse: Res<Input<MouseButton>>,
+    cursor: CursorRaycast,
+    mut edit: TerrainEdit,
+    brush: Query<&BrushPreview>,
+    // water level for the current stroke, set at its start so that a single body has a flat surface
+    mut stroke_level: Local<Option<f32>>,
+) {
+    let Ok(brush) = brush.get_single() else { return };
+    let BrushTool::Water(tool) = brush.tool else { return };
+
+    // strokes end when the mouse is released
+    if !mouse.pressed(MouseButton::Left) {
+        *stroke_level = None;
+        return;
+    }
+
+    let Some((center, layer)) = cursor.surface_point() else { return };
+
+    if tool == WaterTool::Drain {
+        for cell in edit.owned_cells(center.xz(), brush.radius) {
+            edit.terrain.set_water_level(cell, None);
+        }
+
+        return;
+    }
+
+    // strokes started over existing water extend it at the same level
+    let surface = match layer {
+        CollisionLayer::Water => center.y,
+        _ => center.y - SURFACE_OFFSET,
+    };
+
+    let level = match tool {
+        // rivers follow the ground along the stroke, but only ever flow downhill from where it started
+        WaterTool::River => surface.min(stroke_level.unwrap_or(surface)),
+        _ => stroke_level.unwrap_or(surface),
+    };
+    *stroke_level = Some(level);
+
+    // dig out the bottom of the water body, never raising any existing terrain
+    let terrain = &edit.terrain;
+    let new_heights = edit
+        .owned_vertices(center.xz(), brush.radius)
+        .into_iter()
+        .map(|vertex| {
+            let distance = terrain.vertex_position(vertex).xz().distance(center.xz());
+            let bottom = level - tool.depth() * tool.profile(distance / brush.radius);
+
+            (vertex, terrain.height(vertex).min(bottom))
+        })
+        .collect::<Vec<_>>();
+
+    edit.set_heights(new_heights, center.xz(), brush.radius);
+
+    // fill any cells that are now below the water level
+    for cell in edit.owned_cells(center.xz(), brush.radius) {
+        let terrain = &mut edit.terrain;
+        let Some(height) = terrain.height_at(terrain.cell_center(cell)) else { continue };
+        if height >= level {
+            continue;
+        }
+
+        terrain.set_water_level(cell, Some(level));
+        if tool == WaterTool::Pool {
+            terrain.set_surface(cell, SurfaceType::Rock);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::theme::UiTheme;
+use crate::{
+    guest::{Admission, ChangeTicketPrice, StartMarketingCampaign},
+    CurrencyFormat,
+};
+use bevy::prelude::*;
+
+pub struct AdmissionControlsPlugin;
+impl Plugin for AdmissionControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (on_admission_button_press, on_admission_changed));
+    }
+}
+
+/// Amount that the ticket price changes by with each button press
+const PRICE_STEP: f32 = 5.0;
+
+/// Buttons for controlling the zoo's admission
+#[derive(Component)]
+enum AdmissionButton {
+    LowerPrice,
+    RaisePrice,
+    Marketing,
+}
+
+#[derive(Component)]
+struct TicketPriceText;
+
+#[derive(Component)]
+struct MarketingText;
+
+/// Spawns in buttons to change the ticket price and start marketing campaigns
+pub(super) fn spawn_admission_controls(
+    parent: &mut ChildBuilder,
+    admission: &Admission,
+    theme: &UiTheme,
+) {
+    use Val::*;
+
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Px(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn((theme.dark_button(), AdmissionButton::LowerPrice))
+                .with_children(|parent| {
+                    parent.spawn(theme.white_text("-", 18.0));
+                });
+
+            parent.spawn((
+                theme.white_text(&ticket_price_text(admission), 18.0),
+                TicketPriceText,
+            ));
+
+            parent
+                .spawn((theme.dark_button(), AdmissionButton::RaisePrice))
+                .with_children(|parent| {
+                    parent.spawn(theme.white_text("+", 18.0));
+                });
+
+            parent
+                .spawn((theme.dark_button(), AdmissionButton::Marketing))
+                .with_children(|parent| {
+                    parent.spawn((
+                        theme.white_text(&marketing_text(admission), 18.0),
+                        MarketingText,
+                    ));
+                });
+        });
+}
+
+fn ticket_price_text(admission: &Admission) -> String {
+    format!("Tickets {}", admission.ticket_price.comma_separated())
+}
+
+fn marketing_text(admission: &Admission) -> String {
+    match admission.is_marketing() {
+        true => "Marketing...".into(),
+        false => "Marketing".into(),
+    }
+}
+
+fn on_admission_button_press(
+    buttons: Query<(&Interaction, &AdmissionButton), Changed<Interaction>>,
+    mut price_changes: EventWriter<ChangeTicketPrice>,
+    mut campaigns: EventWriter<StartMarketingCampaign>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button {
+            AdmissionButton::LowerPrice => price_changes.send(ChangeTicketPrice {
+                amount: -PRICE_STEP,
+            }),
+            AdmissionButton::RaisePrice => {
+                price_changes.send(ChangeTicketPrice { amount: PRICE_STEP })
+            }
+            AdmissionButton::Marketing => campaigns.send(StartMarketingCampaign),
+        }
+    }
+}
+
+fn on_admission_changed(
+    admission: Res<Admission>,
+    mut price_text: Query<&mut Text, (With<TicketPriceText>, Without<MarketingText>)>,
+    mut marketing: Query<&mut Text, (With<MarketingText>, Without<TicketPriceText>)>,
+) {
+    if !admission.is_changed() {
+        return;
+    }
+
+    for (text, value) in [
+        (price_text.single_mut(), ticket_price_text(&admission)),
+        (marketing.single_mut(), marketing_text(&admission)),
+    ] {
+        text.map_unchanged(|text| &mut text.sections[0].value)
+            .set_if_neq(value);
+    }
+}
@@ -0,0 +1,83 @@
+use super::theme::{ButtonColor, UiTheme};
+use crate::clock::{SimulationSpeed, ZooClock};
+use bevy::prelude::*;
+
+pub struct ClockControlsPlugin;
+impl Plugin for ClockControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (on_speed_button_press, on_clock_changed));
+    }
+}
+
+/// Button that switches the simulation to its speed
+#[derive(Component)]
+struct SpeedButton {
+    speed: SimulationSpeed,
+}
+
+#[derive(Component)]
+struct DateText;
+
+/// Spawns in the current date along with buttons to change the simulation speed
+pub(super) fn spawn_clock_controls(parent: &mut ChildBuilder, clock: &ZooClock, theme: &UiTheme) {
+    use Val::*;
+
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Px(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((theme.white_text(&clock.date(), 18.0), DateText));
+
+            for speed in SimulationSpeed::ALL {
+                parent
+                    .spawn((theme.dark_button(), SpeedButton { speed }))
+                    .with_children(|parent| {
+                        parent.spawn(theme.white_text(speed.name(), 18.0));
+                    });
+            }
+        });
+}
+
+fn on_speed_button_press(
+    buttons: Query<(&Interaction, &SpeedButton), Changed<Interaction>>,
+    mut clock: ResMut<ZooClock>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            clock.set_speed(button.speed);
+        }
+    }
+}
+
+fn on_clock_changed(
+    clock: Res<ZooClock>,
+    theme: Res<UiTheme>,
+    mut date_text: Query<&mut Text, With<DateText>>,
+    mut buttons: Query<(&SpeedButton, &mut ButtonColor)>,
+) {
+    if !clock.is_changed() {
+        return;
+    }
+
+    for text in date_text.iter_mut() {
+        text.map_unchanged(|text| &mut text.sections[0].value)
+            .set_if_neq(clock.date());
+    }
+
+    // highlight the button of the speed that the simulation is running at
+    for (button, mut button_color) in buttons.iter_mut() {
+        let selected = button.speed == clock.speed();
+        if selected != button_color.is_overridden() {
+            match selected {
+                true => button_color.update(theme.accent),
+                false => button_color.revert(),
+            }
+        }
+    }
+}
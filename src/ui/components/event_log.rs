@@ -0,0 +1,54 @@
+use super::{theme::UiTheme, BlockCameraRaycast};
+use crate::zoo::ZooLog;
+use bevy::prelude::*;
+
+pub struct EventLogPlugin;
+impl Plugin for EventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_event_log)
+            .add_systems(Update, on_log_changed);
+    }
+}
+
+/// Number of the most recent log entries that are shown
+const SHOWN_ENTRIES: usize = 5;
+
+/// Marker component for the event log's text bundle
+#[derive(Component)]
+struct EventLogText;
+
+fn setup_event_log(mut commands: Commands, theme: Res<UiTheme>) {
+    use Val::*;
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Px(0.0),
+                    left: Px(0.0),
+                    margin: UiRect::all(Px(16.0)),
+                    padding: UiRect::all(Px(4.0)),
+                    ..default()
+                },
+                background_color: theme.dark.with_a(0.6).into(),
+                ..default()
+            },
+            BlockCameraRaycast,
+        ))
+        .with_children(|parent| {
+            parent.spawn((theme.white_text("", 14.0), EventLogText));
+        });
+}
+
+/// Shows the most recent log entries whenever the log is written to
+fn on_log_changed(log: Res<ZooLog>, mut log_text: Query<&mut Text, With<EventLogText>>) {
+    if !log.is_changed() {
+        return;
+    }
+
+    let entries = log.entries();
+    let recent = &entries[entries.len().saturating_sub(SHOWN_ENTRIES)..];
+
+    log_text.single_mut().sections[0].value = recent.join("\n");
+}
@@ -0,0 +1,166 @@
+use super::theme::UiTheme;
+use crate::{
+    finance::{Bankruptcy, LedgerCategory, RepayLoan, TakeLoan, ZooLedger, ZooLoans},
+    Currency, CurrencyFormat,
+};
+use bevy::prelude::*;
+
+pub struct FinancesPlugin;
+impl Plugin for FinancesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (on_loan_button_press, on_ledger_changed, on_loans_changed),
+        );
+    }
+}
+
+/// Amount borrowed or paid back with each press of a loan button
+const LOAN_STEP: Currency = 5000.0;
+
+/// Buttons for borrowing money and paying it back
+#[derive(Component)]
+enum LoanButton {
+    Borrow,
+    Repay,
+}
+
+/// Marker component for the text breaking down the zoo's income and expenses
+#[derive(Component)]
+struct LedgerText;
+
+/// Marker component for the text describing the zoo's loans and debt
+#[derive(Component)]
+struct LoanText;
+
+/// Spawns in a breakdown of the zoo's finances along with controls for its loans, filled in as they change
+pub(super) fn spawn_finance_panel(parent: &mut ChildBuilder, theme: &UiTheme) {
+    use Val::*;
+
+    parent.spawn((theme.white_text("", 16.0), LedgerText));
+
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                row_gap: Px(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((theme.white_text("", 16.0), LoanText));
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        column_gap: Px(4.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (button, label) in
+                        [(LoanButton::Borrow, "Borrow"), (LoanButton::Repay, "Repay")]
+                    {
+                        parent
+                            .spawn((theme.dark_button(), button))
+                            .with_children(|parent| {
+                                let text = format!("{} {}", label, LOAN_STEP.comma_separated());
+                                parent.spawn(theme.white_text(&text, 16.0));
+                            });
+                    }
+                });
+        });
+}
+
+fn on_loan_button_press(
+    buttons: Query<(&Interaction, &LoanButton), Changed<Interaction>>,
+    mut borrows: EventWriter<TakeLoan>,
+    mut repayments: EventWriter<RepayLoan>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button {
+            LoanButton::Borrow => borrows.send(TakeLoan { amount: LOAN_STEP }),
+            LoanButton::Repay => repayments.send(RepayLoan { amount: LOAN_STEP }),
+        }
+    }
+}
+
+fn on_ledger_changed(ledger: Res<ZooLedger>, mut ledger_text: Query<&mut Text, With<LedgerText>>) {
+    if !ledger.is_changed() {
+        return;
+    }
+
+    let Ok(text) = ledger_text.get_single_mut() else { return };
+
+    let mut lines = vec![
+        format!(
+            "Projected monthly expenses: {}",
+            ledger.projected_expenses.comma_separated()
+        ),
+        format!("Profit this month: {}", ledger.profit().comma_separated()),
+        format!(
+            "Loan principal this month: {}",
+            ledger.this_month(LedgerCategory::Loans).comma_separated()
+        ),
+    ];
+
+    // only list categories that have seen any money recently, with loan principal already shown apart from profit
+    for category in LedgerCategory::ALL {
+        if category == LedgerCategory::Loans {
+            continue;
+        }
+
+        let (this_month, last_month) = (ledger.this_month(category), ledger.last_month(category));
+        if this_month != 0.0 || last_month != 0.0 {
+            lines.push(format!(
+                "{}: {} ({} last month)",
+                category.name(),
+                this_month.comma_separated(),
+                last_month.comma_separated()
+            ));
+        }
+    }
+
+    text.map_unchanged(|text| &mut text.sections[0].value)
+        .set_if_neq(lines.join("\n"));
+}
+
+fn on_loans_changed(
+    loans: Res<ZooLoans>,
+    bankruptcy: Res<Bankruptcy>,
+    mut loan_text: Query<&mut Text, With<LoanText>>,
+) {
+    if !loans.is_changed() && !bankruptcy.is_changed() {
+        return;
+    }
+
+    let Ok(text) = loan_text.get_single_mut() else { return };
+
+    let mut lines = vec![
+        format!("Debt: {}", loans.debt().comma_separated()),
+        format!(
+            "Next repayment: {}",
+            loans.next_repayment().comma_separated()
+        ),
+        format!(
+            "Available credit: {}",
+            loans.available_credit().comma_separated()
+        ),
+    ];
+
+    // warn about how close the zoo is to going bankrupt
+    if bankruptcy.is_declared() {
+        lines.push("The zoo has gone bankrupt".into());
+    } else if let Some(days) = bankruptcy.days_remaining() {
+        lines.push(format!("Bankrupt in {} days unless out of debt", days));
+    }
+
+    text.map_unchanged(|text| &mut text.sections[0].value)
+        .set_if_neq(lines.join("\n"));
+}
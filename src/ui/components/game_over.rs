@@ -0,0 +1,61 @@
+use super::{theme::UiTheme, BlockCameraRaycast};
+use crate::finance::Bankruptcy;
+use bevy::prelude::*;
+
+pub struct GameOverPlugin;
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_game_over)
+            .add_systems(Update, on_bankruptcy_changed);
+    }
+}
+
+/// Marker component for the screen shown once the zoo has gone bankrupt
+#[derive(Component)]
+struct GameOverScreen;
+
+fn setup_game_over(mut commands: Commands, theme: Res<UiTheme>) {
+    use Val::*;
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Px(0.0),
+                    bottom: Px(0.0),
+                    left: Px(0.0),
+                    right: Px(0.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    display: Display::None,
+                    ..default()
+                },
+                background_color: theme.dark.with_a(0.8).into(),
+                ..default()
+            },
+            GameOverScreen,
+            BlockCameraRaycast,
+        ))
+        .with_children(|parent| {
+            parent.spawn(
+                theme
+                    .white_text("The zoo has gone bankrupt\nGame over", 48.0)
+                    .with_text_alignment(TextAlignment::Center),
+            );
+        });
+}
+
+/// Covers the screen once the zoo has gone bankrupt
+fn on_bankruptcy_changed(
+    bankruptcy: Res<Bankruptcy>,
+    mut screen: Query<&mut Style, With<GameOverScreen>>,
+) {
+    if !bankruptcy.is_changed() || !bankruptcy.is_declared() {
+        return;
+    }
+
+    for mut style in screen.iter_mut() {
+        style.display = Display::Flex;
+    }
+}
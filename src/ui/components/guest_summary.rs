@@ -0,0 +1,69 @@
+use super::{theme::UiTheme, BlockCameraRaycast};
+use crate::guest::GuestHappiness;
+use bevy::prelude::*;
+
+pub struct GuestSummaryPlugin;
+impl Plugin for GuestSummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_guest_summary)
+            .add_systems(Update, on_happiness_changed);
+    }
+}
+
+/// Number of the most common complaints that are shown
+const SHOWN_COMPLAINTS: usize = 3;
+
+/// Marker component for the guest summary's text bundle
+#[derive(Component)]
+struct GuestSummaryText;
+
+fn setup_guest_summary(mut commands: Commands, theme: Res<UiTheme>) {
+    use Val::*;
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Px(0.0),
+                    right: Px(0.0),
+                    margin: UiRect::all(Px(16.0)),
+                    padding: UiRect::all(Px(4.0)),
+                    ..default()
+                },
+                background_color: theme.dark.with_a(0.6).into(),
+                ..default()
+            },
+            BlockCameraRaycast,
+        ))
+        .with_children(|parent| {
+            parent.spawn((theme.white_text("", 14.0), GuestSummaryText));
+        });
+}
+
+/// Shows how happy guests are along with their most common complaints
+fn on_happiness_changed(
+    happiness: Res<GuestHappiness>,
+    mut summary_text: Query<&mut Text, With<GuestSummaryText>>,
+) {
+    if !happiness.is_changed() {
+        return;
+    }
+
+    let mut lines = vec![format!(
+        "Guest happiness: {:.0}%",
+        happiness.average * 100.0
+    )];
+    lines.extend(
+        happiness
+            .complaints
+            .iter()
+            .take(SHOWN_COMPLAINTS)
+            .map(|(thought, count)| format!("{}: \"{}\"", count, thought.message())),
+    );
+
+    summary_text
+        .single_mut()
+        .map_unchanged(|text| &mut text.sections[0].value)
+        .set_if_neq(lines.join("\n"));
+}
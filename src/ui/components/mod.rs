@@ -1,5 +1,11 @@
 use bevy::prelude::*;
 
+mod admission;
+mod clock;
+mod event_log;
+mod finances;
+mod game_over;
+mod guest_summary;
 mod message_box;
 mod toolbar;
 
@@ -8,6 +14,15 @@ use super::*;
 pub struct UiComponentsPlugin;
 impl Plugin for UiComponentsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((message_box::MessageBoxPlugin, toolbar::ToolbarPlugin));
+        app.add_plugins((
+            admission::AdmissionControlsPlugin,
+            clock::ClockControlsPlugin,
+            event_log::EventLogPlugin,
+            finances::FinancesPlugin,
+            game_over::GameOverPlugin,
+            guest_summary::GuestSummaryPlugin,
+            message_box::MessageBoxPlugin,
+            toolbar::ToolbarPlugin,
+        ));
     }
 }
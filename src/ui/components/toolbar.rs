@@ -1,11 +1,22 @@
 use super::{
+    admission::spawn_admission_controls,
+    clock::spawn_clock_controls,
+    finances::spawn_finance_panel,
     tabs::{tab_group, HideTabButton, ShowTabButton},
     theme::{ButtonColor, UiTheme},
     BlockCameraRaycast, UiDisplay,
 };
 use crate::{
-    objects::{BarrierData, PropData},
+    clock::ZooClock,
+    guest::{Admission, EntranceGateTool, Guest},
+    land::LandPurchaseTool,
+    objects::{
+        AnimalData, AnimalMarket, AnimalTradeTools, BarrierData, FacilityData, PathData, PropData,
+    },
     placement::{ChangePreview, ClearPreview, PreviewData},
+    rating::ZooRating,
+    staff::{Staff, StaffRole, StaffTasks, StaffTools},
+    terrain::TerrainBrushes,
     zoo::{OnZooBalanceChanged, Zoo},
     CurrencyFormat,
 };
@@ -15,13 +26,35 @@ use std::sync::Arc;
 pub struct ToolbarPlugin;
 impl Plugin for ToolbarPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_toolbar).add_systems(
+        // popup windows need to be spawned before each menu can fill them in
+        app.add_systems(
+            Startup,
+            (
+                setup_toolbar,
+                apply_deferred,
+                (
+                    setup_build_menu,
+                    setup_terrain_menu,
+                    setup_animal_menu,
+                    setup_facilities_menu,
+                    setup_nature_menu,
+                    setup_staff_menu,
+                    setup_finances_menu,
+                ),
+            )
+                .chain(),
+        )
+        .add_systems(
             Update,
             (
                 tab_group::<BuyMenu>,
                 on_buy_button_press,
                 on_preview_change,
                 on_zoo_balance_changed,
+                on_market_changed,
+                update_guest_count,
+                update_rating_text,
+                update_staff_summary,
             ),
         );
     }
@@ -32,6 +65,21 @@ impl Plugin for ToolbarPlugin {
 #[derive(Component)]
 pub struct ZooBalanceText;
 
+#[derive(Component)]
+pub struct GuestCountText;
+
+/// Marker component for the text showing the zoo's star rating and how it is trending
+#[derive(Component)]
+pub struct ZooRatingText;
+
+/// Marker component for the text listing staff and their outstanding tasks
+#[derive(Component)]
+pub struct StaffSummaryText;
+
+/// Container for the buy buttons of animals currently offered on the market
+#[derive(Component)]
+pub struct MarketOfferList;
+
 /// Component for a buy button that stores the event to send when clicked
 #[derive(Component)]
 pub struct BuyButton {
@@ -42,15 +90,19 @@ pub struct BuyButton {
 #[derive(Component, PartialEq)]
 pub(super) enum BuyMenu {
     Build,
+    Terrain,
     Animal,
+    Facilities,
     Nature,
+    Staff,
+    Finances,
 }
 
 pub(super) fn setup_toolbar(
     mut commands: Commands,
-    barriers: Res<Assets<BarrierData>>,
-    props: Res<Assets<PropData>>,
+    admission: Res<Admission>,
     zoo: Res<Zoo>,
+    clock: Res<ZooClock>,
     theme: Res<UiTheme>,
 ) {
     use Val::*;
@@ -86,35 +138,18 @@ pub(super) fn setup_toolbar(
             BlockCameraRaycast,
         ))
         .with_children(|parent| {
-            // build menu
-            parent
-                .spawn((popup_menu.clone(), BuyMenu::Build))
-                .with_children(|parent| {
-                    for (handle_id, barrier) in barriers.iter() {
-                        buy_button(barrier, barriers.get_handle(handle_id), parent, &theme);
-                    }
-                });
-
-            // animal menu
-            parent
-                .spawn((popup_menu.clone(), BuyMenu::Animal))
-                .with_children(|parent| {
-                    parent.spawn(theme.white_text("Animals", 18.0));
-                });
-
-            // nature menu
-            parent
-                .spawn((popup_menu.clone(), BuyMenu::Nature))
-                .with_children(|parent: &mut ChildBuilder<'_, '_, '_>| {
-                    // TODO: nature should probably be sorted by biome when its added
-                    // sort props by name
-                    let mut sorted_props = props.iter().collect::<Vec<_>>();
-                    sorted_props.sort_by_key(|(_, prop)| prop.name());
-
-                    for (handle_id, prop) in sorted_props.into_iter() {
-                        buy_button(prop, props.get_handle(handle_id), parent, &theme);
-                    }
-                });
+            // popup windows for each menu, which are filled in by their own setup systems
+            for menu in [
+                BuyMenu::Build,
+                BuyMenu::Terrain,
+                BuyMenu::Animal,
+                BuyMenu::Facilities,
+                BuyMenu::Nature,
+                BuyMenu::Staff,
+                BuyMenu::Finances,
+            ] {
+                parent.spawn((popup_menu.clone(), menu));
+            }
 
             // toolbar
             parent
@@ -129,11 +164,13 @@ pub(super) fn setup_toolbar(
                     ..default()
                 })
                 .with_children(|parent| {
-                    // currency panel
+                    // currency and clock panel
                     parent
                         .spawn(NodeBundle {
                             style: Style {
                                 flex_basis: Percent(33.3),
+                                align_items: AlignItems::Center,
+                                column_gap: Px(16.0),
                                 ..default()
                             },
                             ..default()
@@ -143,6 +180,7 @@ pub(super) fn setup_toolbar(
                                 theme.white_text(&zoo.balance().comma_separated(), 18.0),
                                 ZooBalanceText,
                             ));
+                            spawn_clock_controls(parent, &clock, &theme);
                         });
 
                     // buttons panel
@@ -169,6 +207,17 @@ pub(super) fn setup_toolbar(
                                     parent.spawn(theme.white_text("Build", 18.0));
                                 });
 
+                            parent
+                                .spawn((
+                                    theme.dark_button(),
+                                    ShowTabButton {
+                                        tab: BuyMenu::Terrain,
+                                    },
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(theme.white_text("Terrain", 18.0));
+                                });
+
                             parent
                                 .spawn((
                                     theme.dark_button(),
@@ -180,6 +229,17 @@ pub(super) fn setup_toolbar(
                                     parent.spawn(theme.white_text("Animals", 18.0));
                                 });
 
+                            parent
+                                .spawn((
+                                    theme.dark_button(),
+                                    ShowTabButton {
+                                        tab: BuyMenu::Facilities,
+                                    },
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(theme.white_text("Facilities", 18.0));
+                                });
+
                             parent
                                 .spawn((
                                     theme.dark_button(),
@@ -191,6 +251,28 @@ pub(super) fn setup_toolbar(
                                     parent.spawn(theme.white_text("Nature", 18.0));
                                 });
 
+                            parent
+                                .spawn((
+                                    theme.dark_button(),
+                                    ShowTabButton {
+                                        tab: BuyMenu::Staff,
+                                    },
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(theme.white_text("Staff", 18.0));
+                                });
+
+                            parent
+                                .spawn((
+                                    theme.dark_button(),
+                                    ShowTabButton {
+                                        tab: BuyMenu::Finances,
+                                    },
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(theme.white_text("Finances", 18.0));
+                                });
+
                             parent
                                 .spawn((theme.dark_button(), HideTabButton::<BuyMenu>::default()))
                                 .with_children(|parent| {
@@ -210,12 +292,147 @@ pub(super) fn setup_toolbar(
                             ..default()
                         })
                         .with_children(|parent| {
-                            parent.spawn(theme.white_text("100 guests", 18.0));
+                            parent.spawn((theme.white_text("0 guests", 18.0), GuestCountText));
+                            parent.spawn((theme.white_text("Unrated", 18.0), ZooRatingText));
+                            spawn_admission_controls(parent, &admission, &theme);
                         });
                 });
         });
 }
 
+/// Spawns the contents of the given menu's popup window
+fn menu_children(
+    commands: &mut Commands,
+    menus: &Query<(Entity, &BuyMenu)>,
+    tab: BuyMenu,
+    spawn_children: impl FnOnce(&mut ChildBuilder),
+) {
+    let Some((menu, _)) = menus.iter().find(|(_, menu)| **menu == tab) else { return };
+    commands.entity(menu).with_children(spawn_children);
+}
+
+fn setup_build_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    gate_tool: Res<EntranceGateTool>,
+    barriers: Res<Assets<BarrierData>>,
+    paths: Res<Assets<PathData>>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Build, |parent| {
+        buy_button(&*gate_tool, gate_tool.clone(), parent, &theme);
+
+        for (handle_id, barrier) in barriers.iter() {
+            buy_button(barrier, barriers.get_handle(handle_id), parent, &theme);
+        }
+
+        for (handle_id, path) in paths.iter() {
+            buy_button(path, paths.get_handle(handle_id), parent, &theme);
+        }
+    });
+}
+
+fn setup_terrain_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    terrain_brushes: Res<TerrainBrushes>,
+    land_tool: Res<LandPurchaseTool>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Terrain, |parent| {
+        buy_button(&*land_tool, land_tool.clone(), parent, &theme);
+
+        for brush in terrain_brushes.brushes.iter() {
+            buy_button(brush, brush.clone(), parent, &theme);
+        }
+    });
+}
+
+fn setup_animal_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    animals: Res<Assets<AnimalData>>,
+    props: Res<Assets<PropData>>,
+    trade_tools: Res<AnimalTradeTools>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Animal, |parent| {
+        // sort animals by species
+        let mut sorted_animals = animals.iter().collect::<Vec<_>>();
+        sorted_animals.sort_by_key(|(_, animal)| animal.name());
+
+        for (handle_id, animal) in sorted_animals.into_iter() {
+            buy_button(animal, animals.get_handle(handle_id), parent, &theme);
+        }
+
+        // followed by props that animals make use of
+        for (handle_id, prop) in props.iter() {
+            if !prop.feature.is_nature() && !prop.feature.is_for_guests() {
+                buy_button(prop, props.get_handle(handle_id), parent, &theme);
+            }
+        }
+
+        // tools for selling and releasing animals
+        for tool in trade_tools.tools.iter() {
+            buy_button(tool, tool.clone(), parent, &theme);
+        }
+
+        // individual animals on the market, filled in as offers change
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ..default()
+            },
+            MarketOfferList,
+        ));
+    });
+}
+
+fn setup_facilities_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    facilities: Res<Assets<FacilityData>>,
+    props: Res<Assets<PropData>>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Facilities, |parent| {
+        for (handle_id, facility) in facilities.iter() {
+            buy_button(facility, facilities.get_handle(handle_id), parent, &theme);
+        }
+
+        // followed by props that guests make use of
+        for (handle_id, prop) in props.iter() {
+            if prop.feature.is_for_guests() {
+                buy_button(prop, props.get_handle(handle_id), parent, &theme);
+            }
+        }
+    });
+}
+
+fn setup_nature_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    props: Res<Assets<PropData>>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Nature, |parent| {
+        // TODO: nature should probably be sorted by biome when its added
+        // sort props by name
+        let mut sorted_props = props
+            .iter()
+            .filter(|(_, prop)| prop.feature.is_nature())
+            .collect::<Vec<_>>();
+        sorted_props.sort_by_key(|(_, prop)| prop.name());
+
+        for (handle_id, prop) in sorted_props.into_iter() {
+            buy_button(prop, props.get_handle(handle_id), parent, &theme);
+        }
+    });
+}
+
 /// Spawns in a buy button built for a given `UiDisplay`-able object with corresponding `PreviewData`
 fn buy_button(
     displayable: &impl UiDisplay,
@@ -301,6 +518,29 @@ pub fn on_preview_change(
     }
 }
 
+pub fn on_market_changed(
+    mut commands: Commands,
+    market: Res<AnimalMarket>,
+    theme: Res<UiTheme>,
+    offer_list: Query<Entity, With<MarketOfferList>>,
+) {
+    if !market.is_changed() {
+        return;
+    }
+
+    let Ok(offer_list) = offer_list.get_single() else { return };
+
+    // rebuild buy buttons for every offer
+    commands
+        .entity(offer_list)
+        .despawn_descendants()
+        .with_children(|parent| {
+            for offer in market.offers() {
+                buy_button(offer, offer.clone(), parent, &theme);
+            }
+        });
+}
+
 pub fn on_zoo_balance_changed(
     mut on_balance_changed: EventReader<OnZooBalanceChanged>,
     mut zoo_balance_text: Query<&mut Text, With<ZooBalanceText>>,
@@ -310,3 +550,101 @@ pub fn on_zoo_balance_changed(
         text.sections[0].value = balance_changed.balance.comma_separated();
     }
 }
+
+pub fn update_guest_count(
+    rating: Res<ZooRating>,
+    guests: Query<(), With<Guest>>,
+    mut guest_count_text: Query<&mut Text, With<GuestCountText>>,
+) {
+    let Ok(text) = guest_count_text.get_single_mut() else { return };
+
+    let count = format!(
+        "{}/{} guests",
+        guests.iter().count(),
+        rating.guest_capacity()
+    );
+    text.map_unchanged(|text| &mut text.sections[0].value)
+        .set_if_neq(count);
+}
+
+pub fn update_rating_text(
+    rating: Res<ZooRating>,
+    mut rating_text: Query<&mut Text, With<ZooRatingText>>,
+) {
+    if !rating.is_changed() {
+        return;
+    }
+    let Ok(text) = rating_text.get_single_mut() else { return };
+
+    text.map_unchanged(|text| &mut text.sections[0].value)
+        .set_if_neq(rating_summary(&rating));
+}
+
+/// Current star rating and how it is trending, or a placeholder until the zoo is first rated
+fn rating_summary(rating: &ZooRating) -> String {
+    match rating.is_rated() {
+        true => format!(
+            "{:.1} stars ({:+.1} this month)",
+            rating.stars(),
+            rating.monthly_trend()
+        ),
+        false => "Unrated".into(),
+    }
+}
+
+pub fn update_staff_summary(
+    tasks: Res<StaffTasks>,
+    staff: Query<&Staff>,
+    mut summary_text: Query<&mut Text, With<StaffSummaryText>>,
+) {
+    let Ok(text) = summary_text.get_single_mut() else { return };
+
+    // list how many of each role are hired, and flag roles that can't keep up with their tasks
+    let summary = StaffRole::ALL
+        .into_iter()
+        .map(|role| {
+            let hired = staff.iter().filter(|member| member.role == role).count();
+            let (waiting, overdue) = tasks.unclaimed(role);
+
+            let mut line = format!(
+                "{}: {} hired, {} tasks waiting",
+                role.name(),
+                hired,
+                waiting
+            );
+            if overdue > 0 {
+                line.push_str(" (understaffed)");
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    text.map_unchanged(|text| &mut text.sections[0].value)
+        .set_if_neq(summary);
+}
+
+fn setup_staff_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    staff_tools: Res<StaffTools>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Staff, |parent| {
+        for tool in staff_tools.tools.iter() {
+            buy_button(tool, tool.clone(), parent, &theme);
+        }
+
+        parent.spawn((theme.white_text("", 16.0), StaffSummaryText));
+    });
+}
+
+fn setup_finances_menu(
+    mut commands: Commands,
+    menus: Query<(Entity, &BuyMenu)>,
+    theme: Res<UiTheme>,
+) {
+    menu_children(&mut commands, &menus, BuyMenu::Finances, |parent| {
+        spawn_finance_panel(parent, &theme);
+    });
+}
@@ -33,6 +33,11 @@ impl ButtonColor {
     pub fn revert(&mut self) {
         self.override_color = None;
     }
+
+    /// Returns true if the button's default color is currently overridden
+    pub fn is_overridden(&self) -> bool {
+        self.override_color.is_some()
+    }
 }
 
 /// Group of colors that are used for styling the ui
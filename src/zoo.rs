@@ -1,25 +1,43 @@
-use crate::Currency;
+use crate::{finance::LedgerCategory, Currency};
 pub use bevy::prelude::*;
 
 pub struct ZooPlugin;
 impl Plugin for ZooPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Zoo>()
+            .init_resource::<ZooLog>()
             .add_event::<ZooBalanceChange>()
             .add_event::<OnZooBalanceChanged>()
-            .add_systems(Update, handle_balance_change);
+            .add_event::<ZooReputationChange>()
+            .add_event::<ZooLogMessage>()
+            .add_systems(
+                Update,
+                (
+                    handle_balance_change,
+                    handle_reputation_change,
+                    handle_log_message,
+                ),
+            );
     }
 }
 
 #[derive(Resource)]
 pub struct Zoo {
     balance: Currency,
+    reputation: f32,
 }
 
+// constants for zoo reputation
+const MAX_REPUTATION: f32 = 100.0;
+const INITIAL_REPUTATION: f32 = 50.0;
+
 impl Default for Zoo {
     fn default() -> Self {
         // TEMP: giving an initial balance for testing
-        Self { balance: 5000.0 }
+        Self {
+            balance: 5000.0,
+            reputation: INITIAL_REPUTATION,
+        }
     }
 }
 
@@ -28,12 +46,19 @@ impl Zoo {
     pub fn balance(&self) -> Currency {
         self.balance
     }
+
+    /// Returns the current reputation of the zoo, from 0 to 100
+    pub fn reputation(&self) -> f32 {
+        self.reputation
+    }
 }
 
 /// Event for systems to request a balance change to the `Zoo`
 #[derive(Event)]
 pub struct ZooBalanceChange {
     pub amount: Currency,
+    /// Part of the ledger that the change is recorded under
+    pub category: LedgerCategory,
 }
 
 /// Callback event for systems that want to be notified of a balance change
@@ -55,3 +80,44 @@ fn handle_balance_change(
         });
     }
 }
+
+/// Event for systems to request a reputation change to the `Zoo`
+#[derive(Event)]
+pub struct ZooReputationChange {
+    pub amount: f32,
+}
+
+fn handle_reputation_change(
+    mut zoo: ResMut<Zoo>,
+    mut request_reader: EventReader<ZooReputationChange>,
+) {
+    for change_reputation_event in request_reader.iter() {
+        zoo.reputation =
+            (zoo.reputation + change_reputation_event.amount).clamp(0.0, MAX_REPUTATION);
+    }
+}
+
+/// Record of noteworthy things that have happened in the zoo, oldest first
+#[derive(Resource, Default)]
+pub struct ZooLog {
+    entries: Vec<String>,
+}
+
+impl ZooLog {
+    /// Returns every entry in the log, oldest first
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+}
+
+/// Event for systems to add an entry to the `ZooLog`
+#[derive(Event)]
+pub struct ZooLogMessage {
+    pub message: String,
+}
+
+fn handle_log_message(mut log: ResMut<ZooLog>, mut request_reader: EventReader<ZooLogMessage>) {
+    for log_message_event in request_reader.iter() {
+        log.entries.push(log_message_event.message.clone());
+    }
+}